@@ -1,17 +1,26 @@
 // src/aws/cloudwatch.rs
+use crate::exporter_metrics::{
+    AWS_API_CALLS, AWS_API_RETRIES, BATCH_COLLECTION_DURATION, TARGET_COLLECTION_DURATION,
+};
 use aws_sdk_cloudwatch::operation::get_metric_data::GetMetricDataOutput;
-use aws_sdk_cloudwatch::types::{Dimension, Metric, MetricDataQuery, MetricStat};
+use aws_sdk_cloudwatch::types::{
+    Dimension, Metric, MetricDataQuery, MetricStat, StandardUnit, StatusCode,
+};
 use aws_sdk_cloudwatch::{Client, Error as AwsError};
 use aws_smithy_types::DateTime as SmithyDateTime;
 use chrono::{DateTime, Duration, Utc};
+use futures_util::stream::{self, StreamExt};
+use std::collections::HashMap;
+use std::ops::Range;
+use std::time::Instant;
 use thiserror::Error;
 use tokio::time::sleep;
-use tracing::{error, info, warn};
+use tracing::{info, warn};
 
 #[derive(Error, Debug)]
 pub enum CloudWatchError {
     #[error("AWS API 에러: {0}")]
-    AwsError(#[from] AwsError),
+    AwsError(#[from] Box<AwsError>),
 
     #[error("잘못된 매개변수: {0}")]
     InvalidParameter(String),
@@ -31,6 +40,12 @@ pub struct MetricConfig {
     pub stat: String,
     pub retry_attempts: u32,
     pub retry_delay: Duration,
+    /// 동시에 실행할 GetMetricData 배치 수
+    pub max_concurrent_requests: usize,
+    /// GetMetricData 호출 한 번(페이지, 재시도마다)의 제한 시간
+    pub request_timeout: Duration,
+    /// 대상 하나의 데이터를 조회하는 전체 제한 시간. 대상이 포함된 배치의 모든 페이지와 재시도에 적용됩니다
+    pub target_timeout: Duration,
 }
 
 impl Default for MetricConfig {
@@ -40,10 +55,81 @@ impl Default for MetricConfig {
             stat: "Average".to_string(),
             retry_attempts: 3,
             retry_delay: Duration::seconds(1),
+            max_concurrent_requests: 4,
+            request_timeout: Duration::seconds(30),
+            target_timeout: Duration::seconds(120),
         }
     }
 }
 
+/// GetMetricData 요청 하나에 담을 수 있는 최대 쿼리 수
+pub const MAX_QUERIES_PER_REQUEST: usize = 500;
+
+/// 배치 조회할 메트릭 하나 (네임스페이스, 메트릭 이름, 차원)
+///
+/// `stats`가 비어 있거나 `period`를 지정하지 않으면 [`MetricConfig`]의 값을 사용합니다.
+/// 통계마다 별도의 GetMetricData 쿼리가 생성됩니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MetricQuery {
+    pub namespace: String,
+    pub metric_name: String,
+    pub dimension_name: String,
+    pub dimension_value: String,
+    pub stats: Vec<String>,
+    pub period: Option<i32>,
+    pub unit: Option<String>,
+}
+
+impl MetricQuery {
+    pub fn new(
+        namespace: impl Into<String>,
+        metric_name: impl Into<String>,
+        dimension_name: impl Into<String>,
+        dimension_value: impl Into<String>,
+    ) -> Self {
+        Self {
+            namespace: namespace.into(),
+            metric_name: metric_name.into(),
+            dimension_name: dimension_name.into(),
+            dimension_value: dimension_value.into(),
+            stats: Vec::new(),
+            period: None,
+            unit: None,
+        }
+    }
+}
+
+/// 쿼리 하나의 통계 하나에 대한 조회 결과. 여러 페이지에 걸친 값은 하나로 합쳐집니다.
+#[derive(Debug, Clone)]
+pub struct MetricQueryResult {
+    pub query: MetricQuery,
+    pub stat: String,
+    pub timestamps: Vec<DateTime<Utc>>,
+    pub values: Vec<f64>,
+    /// 마지막 페이지 기준 상태 코드. 응답에서 누락된 쿼리는 `None`
+    pub status: Option<StatusCode>,
+}
+
+impl MetricQueryResult {
+    pub fn is_complete(&self) -> bool {
+        matches!(self.status, Some(StatusCode::Complete))
+    }
+
+    /// 가장 최근 데이터 포인트 (타임스탬프, 값). 응답 배열의 순서와 무관하게 시각으로 고릅니다.
+    pub fn latest(&self) -> Option<(DateTime<Utc>, f64)> {
+        self.timestamps
+            .iter()
+            .copied()
+            .zip(self.values.iter().copied())
+            .max_by_key(|(timestamp, _)| *timestamp)
+    }
+
+    /// 로그와 자체 메트릭에 쓰이는 상태 이름
+    pub fn status_label(&self) -> &str {
+        self.status.as_ref().map_or("Missing", |s| s.as_str())
+    }
+}
+
 pub struct CloudWatchCollector {
     client: Client,
     config: MetricConfig,
@@ -54,38 +140,275 @@ impl CloudWatchCollector {
         Self { client, config }
     }
 
-    pub async fn collect_all_metrics(
-        &mut self,
-        metrics: Vec<(&str, &str, &str, &str)>,
+    pub fn set_config(&mut self, config: MetricConfig) {
+        self.config = config;
+    }
+
+    /// 여러 인스턴스의 쿼리를 최대 500개 단위의 GetMetricData 요청으로 묶어 조회합니다.
+    ///
+    /// 응답은 순서가 아닌 쿼리 Id로 원래 쿼리에 매핑됩니다. 일부 배치가 실패해도
+    /// 나머지 배치의 결과는 반환하며, 모든 배치가 실패한 경우에만 에러를 반환합니다.
+    pub async fn collect_batched(
+        &self,
+        queries: Vec<MetricQuery>,
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
-    ) -> Result<GetMetricDataOutput> {
-        let mut queries = Vec::with_capacity(metrics.len());
+    ) -> Result<Vec<MetricQueryResult>> {
+        // 쿼리 x 통계 조합마다 하나의 MetricDataQuery를 만듭니다
+        let entries: Vec<(usize, &str)> = queries
+            .iter()
+            .enumerate()
+            .flat_map(|(idx, query)| self.stats_for(query).into_iter().map(move |s| (idx, s)))
+            .collect();
+
+        let mut data_queries = Vec::with_capacity(entries.len());
+        // 쿼리 Id -> entries 인덱스 (대상, 메트릭, 통계)
+        let mut query_ids: HashMap<String, usize> = HashMap::with_capacity(entries.len());
+
+        for (entry_idx, (idx, stat)) in entries.iter().enumerate() {
+            let metric_stat = self.build_metric_stat(&queries[*idx], stat)?;
+            let id = format!("q{}", entry_idx);
+            data_queries.push(
+                MetricDataQuery::builder()
+                    .id(&id)
+                    .metric_stat(metric_stat)
+                    .return_data(true)
+                    .build(),
+            );
+            query_ids.insert(id, entry_idx);
+        }
+
+        let mut results: Vec<Option<MetricQueryResult>> = vec![None; entries.len()];
+        let mut last_error = None;
+        let mut succeeded = 0;
+        let batches = Self::pack_batches(&entries, &queries);
+        let batch_count = batches.len();
+
+        // 배치는 최대 max_concurrent_requests개까지 동시에 조회합니다.
+        // 느리거나 실패한 배치는 그 배치에 포함된 대상에만 영향을 줍니다.
+        let target_timeout = self.config.target_timeout.to_std().unwrap_or_default();
+        let fetched: Vec<_> = stream::iter(batches.into_iter().enumerate())
+            .map(|(batch_idx, range)| {
+                let batch = data_queries[range.clone()].to_vec();
+                async move {
+                    let started = Instant::now();
+                    let pages = tokio::time::timeout(
+                        target_timeout,
+                        self.fetch_all_pages(start_time, end_time, batch),
+                    )
+                    .await
+                    .unwrap_or_else(|_| {
+                        Err(CloudWatchError::Timeout(format!(
+                            "대상 조회 제한 시간({:?})을 넘었습니다",
+                            target_timeout
+                        )))
+                    });
+                    (batch_idx, range, pages, started.elapsed())
+                }
+            })
+            .buffer_unordered(self.config.max_concurrent_requests.max(1))
+            .collect()
+            .await;
+
+        // 대상(차원) -> 대상이 포함된 배치 중 가장 오래 걸린 조회 시간
+        let mut target_latency: HashMap<(&str, &str), std::time::Duration> = HashMap::new();
+        let mut batch_pages = Vec::with_capacity(fetched.len());
+        for (batch_idx, range, pages, latency) in fetched {
+            // 배치 하나의 조회 시간 (모든 페이지와 재시도 포함, 실패한 배치도 기록)
+            BATCH_COLLECTION_DURATION.observe(latency.as_secs_f64());
+            for &(idx, _) in &entries[range.clone()] {
+                let query = &queries[idx];
+                let slowest = target_latency
+                    .entry((&query.dimension_name, &query.dimension_value))
+                    .or_default();
+                *slowest = (*slowest).max(latency);
+            }
+            let pages = match pages {
+                Ok(pages) => pages,
+                Err(e) => {
+                    warn!(
+                        "GetMetricData 배치 실패 ({}/{}, 쿼리 {}개, {:?}): {}",
+                        batch_idx + 1,
+                        batch_count,
+                        range.len(),
+                        latency,
+                        e
+                    );
+                    last_error = Some(e);
+                    continue;
+                }
+            };
+            succeeded += 1;
+
+            // 성공한 배치의 쿼리는 응답에 없더라도 결과(status = None)로 남깁니다
+            for entry_idx in range.clone() {
+                let (idx, stat) = entries[entry_idx];
+                results[entry_idx] = Some(MetricQueryResult {
+                    query: queries[idx].clone(),
+                    stat: stat.to_string(),
+                    timestamps: Vec::new(),
+                    values: Vec::new(),
+                    status: None,
+                });
+            }
+            batch_pages.push((range, pages));
+        }
+
+        for latency in target_latency.values() {
+            TARGET_COLLECTION_DURATION.observe(latency.as_secs_f64());
+        }
+
+        for (range, pages) in batch_pages {
+            for page in &pages {
+                for message in page.messages() {
+                    warn!(
+                        "CloudWatch 메시지: {} - {}",
+                        message.code().unwrap_or_default(),
+                        message.value().unwrap_or_default()
+                    );
+                }
+
+                for data in page.metric_data_results() {
+                    let Some(result) = data
+                        .id()
+                        .and_then(|id| query_ids.get(id))
+                        .and_then(|&idx| results[idx].as_mut())
+                    else {
+                        warn!("알 수 없는 쿼리 Id 응답 무시: {:?}", data.id());
+                        continue;
+                    };
+
+                    for message in data.messages() {
+                        warn!(
+                            "CloudWatch 메시지 ({}={}, {}): {} - {}",
+                            result.query.dimension_name,
+                            result.query.dimension_value,
+                            result.query.metric_name,
+                            message.code().unwrap_or_default(),
+                            message.value().unwrap_or_default()
+                        );
+                    }
+
+                    result
+                        .timestamps
+                        .extend(data.timestamps().iter().filter_map(to_chrono));
+                    result.values.extend_from_slice(data.values());
+                    // 페이지가 이어지면 마지막 페이지의 상태가 최종 상태입니다
+                    result.status = data.status_code().cloned();
+                }
+            }
+
+            for result in results[range].iter().flatten() {
+                if !result.is_complete() {
+                    warn!(
+                        "불완전한 메트릭 결과 - {}={}, 메트릭: {}, 상태: {}",
+                        result.query.dimension_name,
+                        result.query.dimension_value,
+                        result.query.metric_name,
+                        result.status_label()
+                    );
+                }
+            }
+        }
+
+        if succeeded == 0 {
+            if let Some(e) = last_error {
+                return Err(e);
+            }
+        }
+
+        Ok(results.into_iter().flatten().collect())
+    }
+
+    /// 쿼리를 최대 500개 단위의 배치(entries 인덱스 범위)로 나눕니다.
+    ///
+    /// 같은 대상(차원)의 연속된 쿼리는 가능한 한 같은 배치에 넣어, 배치 하나가
+    /// 느리거나 실패해도 영향을 받는 대상이 최소가 되도록 합니다.
+    fn pack_batches(entries: &[(usize, &str)], queries: &[MetricQuery]) -> Vec<Range<usize>> {
+        let target_of = |entry: &(usize, &str)| {
+            let query = &queries[entry.0];
+            (&query.dimension_name, &query.dimension_value)
+        };
+
+        let mut batches = Vec::new();
+        let mut batch_start = 0;
+        let mut group_start = 0;
 
-        for (idx, (namespace, metric_name, dimension_name, dimension_value)) in
-            metrics.into_iter().enumerate()
-        {
-            let metric_stat =
-                self.build_metric_stat(namespace, metric_name, dimension_name, dimension_value)?;
-            let query = MetricDataQuery::builder()
-                .id(format!("m{}", idx))
-                .metric_stat(metric_stat)
-                .return_data(true)
-                .build();
-            queries.push(query);
+        for end in 1..=entries.len() {
+            if end < entries.len() && target_of(&entries[end]) == target_of(&entries[group_start]) {
+                continue;
+            }
+
+            // [group_start, end)는 한 대상의 쿼리입니다
+            if end - batch_start > MAX_QUERIES_PER_REQUEST {
+                if group_start > batch_start {
+                    batches.push(batch_start..group_start);
+                    batch_start = group_start;
+                }
+                // 한 대상의 쿼리가 500개를 넘으면 나눌 수밖에 없습니다
+                while end - batch_start > MAX_QUERIES_PER_REQUEST {
+                    batches.push(batch_start..batch_start + MAX_QUERIES_PER_REQUEST);
+                    batch_start += MAX_QUERIES_PER_REQUEST;
+                }
+            }
+            group_start = end;
+        }
+        if batch_start < entries.len() {
+            batches.push(batch_start..entries.len());
         }
 
-        self.call_with_retry(start_time, end_time, queries).await
+        batches
     }
 
-    fn build_metric_stat(
+    /// NextToken이 없을 때까지 한 배치의 모든 페이지를 조회합니다.
+    async fn fetch_all_pages(
         &self,
-        namespace: &str,
-        metric_name: &str,
-        dimension_name: &str,
-        dimension_value: &str,
-    ) -> Result<MetricStat> {
-        if namespace.is_empty() || metric_name.is_empty() {
+        start_time: DateTime<Utc>,
+        end_time: DateTime<Utc>,
+        queries: Vec<MetricDataQuery>,
+    ) -> Result<Vec<GetMetricDataOutput>> {
+        let mut pages = Vec::new();
+        let mut next_token = None;
+
+        loop {
+            let response = self
+                .call_with_retry(start_time, end_time, queries.clone(), next_token.clone())
+                .await?;
+            next_token = response.next_token().map(String::from);
+            pages.push(response);
+
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(pages)
+    }
+
+    fn stats_for<'a>(&'a self, query: &'a MetricQuery) -> Vec<&'a str> {
+        if query.stats.is_empty() {
+            vec![self.config.stat.as_str()]
+        } else {
+            query.stats.iter().map(String::as_str).collect()
+        }
+    }
+
+    fn period_for(&self, query: &MetricQuery) -> i32 {
+        query.period.unwrap_or(self.config.period)
+    }
+
+    /// 조회 구간. 기본 5분이며, 긴 period를 가진 쿼리가 있으면 최소 두 주기를 포함하도록 늘립니다.
+    pub fn lookback(&self, queries: &[MetricQuery]) -> Duration {
+        let longest = queries
+            .iter()
+            .map(|query| self.period_for(query))
+            .max()
+            .unwrap_or(self.config.period);
+        Duration::minutes(5).max(Duration::seconds(longest as i64 * 2))
+    }
+
+    fn build_metric_stat(&self, query: &MetricQuery, stat: &str) -> Result<MetricStat> {
+        if query.namespace.is_empty() || query.metric_name.is_empty() {
             return Err(CloudWatchError::InvalidParameter(
                 "namespace와 metric_name은 비어있을 수 없습니다.".to_string(),
             ));
@@ -94,18 +417,19 @@ impl CloudWatchCollector {
         Ok(MetricStat::builder()
             .metric(
                 Metric::builder()
-                    .namespace(namespace)
-                    .metric_name(metric_name)
+                    .namespace(&query.namespace)
+                    .metric_name(&query.metric_name)
                     .dimensions(
                         Dimension::builder()
-                            .name(dimension_name)
-                            .value(dimension_value)
+                            .name(&query.dimension_name)
+                            .value(&query.dimension_value)
                             .build(),
                     )
                     .build(),
             )
-            .period(self.config.period)
-            .stat(&self.config.stat)
+            .period(self.period_for(query))
+            .stat(stat)
+            .set_unit(query.unit.as_deref().map(StandardUnit::from))
             .build())
     }
 
@@ -114,34 +438,50 @@ impl CloudWatchCollector {
         start_time: DateTime<Utc>,
         end_time: DateTime<Utc>,
         queries: Vec<MetricDataQuery>,
+        next_token: Option<String>,
     ) -> Result<GetMetricDataOutput> {
         let mut attempts = 0;
         let mut last_error_message = String::new();
+        let mut timed_out = false;
 
         let start_smithy = SmithyDateTime::from_secs(start_time.timestamp());
         let end_smithy = SmithyDateTime::from_secs(end_time.timestamp());
 
         while attempts < self.config.retry_attempts {
+            if attempts > 0 {
+                AWS_API_RETRIES
+                    .with_label_values(&["cloudwatch", "GetMetricData"])
+                    .inc();
+            }
+
             match tokio::time::timeout(
-                std::time::Duration::from_secs(30),
+                self.config.request_timeout.to_std().unwrap_or_default(),
                 self.client
                     .get_metric_data()
                     .start_time(start_smithy)
                     .end_time(end_smithy)
                     .set_metric_data_queries(Some(queries.clone()))
+                    .set_next_token(next_token.clone())
                     .send(),
             )
             .await
             {
                 Ok(result) => match result {
                     Ok(response) => {
+                        AWS_API_CALLS
+                            .with_label_values(&["cloudwatch", "GetMetricData", "success"])
+                            .inc();
                         if attempts > 0 {
                             info!("재시도 성공 (시도 횟수: {})", attempts + 1);
                         }
                         return Ok(response);
                     }
                     Err(err) => {
+                        AWS_API_CALLS
+                            .with_label_values(&["cloudwatch", "GetMetricData", "error"])
+                            .inc();
                         last_error_message = format!("{:?}", err);
+                        timed_out = false;
                         warn!(
                             "API 호출 실패 (시도 횟수: {}): {}",
                             attempts + 1,
@@ -150,9 +490,12 @@ impl CloudWatchCollector {
                     }
                 },
                 Err(_) => {
+                    AWS_API_CALLS
+                        .with_label_values(&["cloudwatch", "GetMetricData", "timeout"])
+                        .inc();
                     last_error_message = "API 호출 타임아웃".to_string();
+                    timed_out = true;
                     warn!("API 호출 타임아웃 (시도 횟수: {})", attempts + 1);
-                    return Err(CloudWatchError::Timeout(last_error_message));
                 }
             }
 
@@ -162,37 +505,406 @@ impl CloudWatchCollector {
             }
         }
 
-        Err(CloudWatchError::RetryExhausted(format!(
+        let message = format!(
             "최대 재시도 횟수({})를 초과했습니다: {}",
             self.config.retry_attempts, last_error_message
-        )))
+        );
+        // 마지막 시도가 제한 시간을 넘겼으면 타임아웃으로 보고합니다
+        if timed_out {
+            Err(CloudWatchError::Timeout(message))
+        } else {
+            Err(CloudWatchError::RetryExhausted(message))
+        }
     }
 }
 
+/// CloudWatch 표준 통계 또는 확장 통계(p99, tm99, tm(10%:90%) 등) 형식인지 확인합니다.
+pub fn is_valid_statistic(stat: &str) -> bool {
+    const STANDARD: [&str; 5] = ["Average", "Sum", "Minimum", "Maximum", "SampleCount"];
+    const EXTENDED_PREFIXES: [&str; 6] = ["p", "tm", "wm", "tc", "ts", "pr"];
+
+    if STANDARD.contains(&stat) || stat.eq_ignore_ascii_case("iqm") {
+        return true;
+    }
+
+    let lower = stat.to_ascii_lowercase();
+    EXTENDED_PREFIXES.iter().any(|prefix| {
+        lower.strip_prefix(prefix).is_some_and(|rest| {
+            // p99, p99.9 형식 또는 tm(10%:90%) 같은 구간 형식
+            (!rest.is_empty() && rest.chars().all(|c| c.is_ascii_digit() || c == '.'))
+                || (rest.starts_with('(') && rest.ends_with(')'))
+        })
+    })
+}
+
+fn to_chrono(timestamp: &SmithyDateTime) -> Option<DateTime<Utc>> {
+    DateTime::from_timestamp(timestamp.secs(), timestamp.subsec_nanos())
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aws_sdk_cloudwatch::config::Config;
-    use aws_smithy_types::test_connection::TestConnection;
+    use crate::aws::test_util::ReplayConnector;
+    use aws_sdk_cloudwatch::config::{Config, Credentials, Region};
 
-    fn create_test_client() -> Client {
-        let conn = TestConnection::new(Vec::new());
+    fn create_test_client(connector: &ReplayConnector) -> Client {
         let conf = Config::builder()
             .behavior_version_latest()
-            .connection_provider(conn)
+            .region(Region::new("ap-northeast-2"))
+            .credentials_provider(Credentials::new("test", "test", None, None, "test"))
+            .http_client(connector.http_client())
             .build();
         Client::from_conf(conf)
     }
 
+    fn metric_data_response(results: &[(usize, f64)], next_token: Option<&str>) -> String {
+        let members: String = results
+            .iter()
+            .map(|(idx, value)| {
+                format!(
+                    "<member><Id>q{}</Id><Timestamps><member>2025-01-01T00:00:00Z</member></Timestamps>\
+                     <Values><member>{}</member></Values><StatusCode>Complete</StatusCode></member>",
+                    idx, value
+                )
+            })
+            .collect();
+        format!(
+            r#"<GetMetricDataResponse xmlns="http://monitoring.amazonaws.com/doc/2010-08-01/">
+  <GetMetricDataResult><MetricDataResults>{}</MetricDataResults>{}</GetMetricDataResult>
+</GetMetricDataResponse>"#,
+            members,
+            next_token
+                .map(|t| format!("<NextToken>{}</NextToken>", t))
+                .unwrap_or_default()
+        )
+    }
+
+    fn instance_queries(instances: usize, metrics: usize) -> Vec<MetricQuery> {
+        (0..instances)
+            .flat_map(|i| {
+                (0..metrics).map(move |m| {
+                    MetricQuery::new(
+                        "AWS/RDS",
+                        format!("Metric{}", m),
+                        "DBInstanceIdentifier",
+                        format!("db-{}", i),
+                    )
+                })
+            })
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_batches_respect_query_limit() {
+        // 60개 인스턴스 x 20개 메트릭 = 1200개 쿼리 -> 500, 500, 200
+        // 배치는 동시에 조회되므로 응답 순서와 무관하게 Id로 매핑되어야 합니다
+        let connector = ReplayConnector::new([
+            metric_data_response(&[(0, 1.0)], None),
+            metric_data_response(&[(500, 2.0)], None),
+            metric_data_response(&[(1199, 3.0)], None),
+        ]);
+        let collector =
+            CloudWatchCollector::new(create_test_client(&connector), MetricConfig::default());
+
+        let results = collector
+            .collect_batched(
+                instance_queries(60, 20),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        let mut query_counts: Vec<usize> = connector
+            .requests()
+            .iter()
+            .map(|body| body.matches(".Id=").count())
+            .collect();
+        query_counts.sort_unstable();
+        assert_eq!(query_counts, vec![200, 500, 500]);
+        assert_eq!(results.len(), 1200);
+        assert_eq!(results[1199].query.dimension_value, "db-59");
+        assert_eq!(results[1199].query.metric_name, "Metric19");
+        assert_eq!(results[1199].values, vec![3.0]);
+        assert_eq!(results.iter().filter(|r| r.is_complete()).count(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_timed_out_request_is_retried() {
+        let connector =
+            ReplayConnector::new([metric_data_response(&[(0, 1.0)], None)]).stalling_first();
+        let config = MetricConfig {
+            retry_delay: Duration::zero(),
+            request_timeout: Duration::milliseconds(50),
+            ..MetricConfig::default()
+        };
+        let collector = CloudWatchCollector::new(create_test_client(&connector), config);
+
+        let results = collector
+            .collect_batched(
+                instance_queries(1, 1),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(connector.requests().len(), 2);
+        assert_eq!(results[0].values, vec![1.0]);
+    }
+
+    #[tokio::test]
+    async fn test_repeated_timeouts_are_reported_as_timeout() {
+        let connector = ReplayConnector::default().stalling_first();
+        let config = MetricConfig {
+            retry_attempts: 1,
+            request_timeout: Duration::milliseconds(50),
+            ..MetricConfig::default()
+        };
+        let collector = CloudWatchCollector::new(create_test_client(&connector), config);
+
+        let result = collector
+            .collect_batched(
+                instance_queries(1, 1),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await;
+
+        assert!(matches!(result, Err(CloudWatchError::Timeout(_))));
+    }
+
+    #[tokio::test]
+    async fn test_target_timeout_drops_only_the_slow_batch() {
+        // 인스턴스 2개 x 메트릭 300개 -> 배치 2개. 먼저 보낸 요청은 응답하지 않습니다
+        let connector = ReplayConnector::new([metric_data_response(&[(0, 1.0), (300, 2.0)], None)])
+            .stalling_first();
+        let config = MetricConfig {
+            target_timeout: Duration::milliseconds(100),
+            ..MetricConfig::default()
+        };
+        let collector = CloudWatchCollector::new(create_test_client(&connector), config);
+        let observed = TARGET_COLLECTION_DURATION.get_sample_count();
+
+        let results = collector
+            .collect_batched(
+                instance_queries(2, 300),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 300);
+        assert!(results
+            .iter()
+            .all(|r| r.query.dimension_value == results[0].query.dimension_value));
+        assert_eq!(results.iter().filter(|r| r.is_complete()).count(), 1);
+        assert!(TARGET_COLLECTION_DURATION.get_sample_count() >= observed + 2);
+    }
+
+    #[test]
+    fn test_batches_keep_targets_together() {
+        // 30개 인스턴스 x 30개 메트릭: 인스턴스 16개(480개)씩 묶이고 나머지는 다음 배치로
+        let queries = instance_queries(30, 30);
+        let entries: Vec<(usize, &str)> = (0..queries.len()).map(|idx| (idx, "Average")).collect();
+        assert_eq!(
+            CloudWatchCollector::pack_batches(&entries, &queries),
+            vec![0..480, 480..900]
+        );
+
+        // 한 대상의 쿼리가 500개를 넘으면 그 대상만 나뉩니다
+        let mut queries = instance_queries(1, 700);
+        queries.extend(instance_queries(2, 100).into_iter().skip(100));
+        let entries: Vec<(usize, &str)> = (0..queries.len()).map(|idx| (idx, "Average")).collect();
+        assert_eq!(
+            CloudWatchCollector::pack_batches(&entries, &queries),
+            vec![0..500, 500..800]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_pagination_and_id_demultiplexing() {
+        // 두 번째 페이지는 순서가 뒤바뀌어 도착하고, 첫 쿼리의 값이 이어서 도착합니다
+        let connector = ReplayConnector::new([
+            metric_data_response(&[(0, 1.0)], Some("page-2")),
+            metric_data_response(&[(2, 30.0), (0, 2.0), (1, 20.0)], None),
+        ]);
+        let collector =
+            CloudWatchCollector::new(create_test_client(&connector), MetricConfig::default());
+
+        let results = collector
+            .collect_batched(
+                instance_queries(3, 1),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        assert!(connector.requests()[1].contains("NextToken=page-2"));
+        let by_instance: Vec<(&str, Vec<f64>)> = results
+            .iter()
+            .map(|r| (r.query.dimension_value.as_str(), r.values.clone()))
+            .collect();
+        assert_eq!(
+            by_instance,
+            vec![
+                ("db-0", vec![1.0, 2.0]),
+                ("db-1", vec![20.0]),
+                ("db-2", vec![30.0])
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_one_query_per_statistic() {
+        let connector = ReplayConnector::new([metric_data_response(&[(1, 5.0), (2, 9.0)], None)]);
+        let collector =
+            CloudWatchCollector::new(create_test_client(&connector), MetricConfig::default());
+        let query = MetricQuery {
+            stats: vec![
+                "Average".to_string(),
+                "Maximum".to_string(),
+                "p99".to_string(),
+            ],
+            ..MetricQuery::new("AWS/RDS", "ReadLatency", "DBInstanceIdentifier", "db-0")
+        };
+
+        let results = collector
+            .collect_batched(
+                vec![
+                    query,
+                    MetricQuery::new("AWS/RDS", "CPUUtilization", "DBInstanceIdentifier", "db-0"),
+                ],
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        let request = &connector.requests()[0];
+        assert_eq!(request.matches(".Id=").count(), 4);
+        assert!(request.contains("MetricStat.Stat=p99"));
+
+        let stats: Vec<(&str, &str, Vec<f64>)> = results
+            .iter()
+            .map(|r| {
+                (
+                    r.query.metric_name.as_str(),
+                    r.stat.as_str(),
+                    r.values.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            stats,
+            vec![
+                ("ReadLatency", "Average", vec![]),
+                ("ReadLatency", "Maximum", vec![5.0]),
+                ("ReadLatency", "p99", vec![9.0]),
+                ("CPUUtilization", "Average", vec![]),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_status_codes_and_missing_results() {
+        let response = r#"<GetMetricDataResponse xmlns="http://monitoring.amazonaws.com/doc/2010-08-01/">
+  <GetMetricDataResult>
+    <MetricDataResults>
+      <member>
+        <Id>q2</Id><Values><member>7.0</member></Values><StatusCode>Complete</StatusCode>
+      </member>
+      <member>
+        <Id>q0</Id><Values><member>1.0</member></Values><StatusCode>PartialData</StatusCode>
+        <Messages><member><Code>MaxQueryTimeRangeExceed</Code><Value>truncated</Value></member></Messages>
+      </member>
+      <member>
+        <Id>unexpected</Id><Values><member>9.9</member></Values><StatusCode>Complete</StatusCode>
+      </member>
+    </MetricDataResults>
+  </GetMetricDataResult>
+</GetMetricDataResponse>"#;
+        let connector = ReplayConnector::new([response]);
+        let collector =
+            CloudWatchCollector::new(create_test_client(&connector), MetricConfig::default());
+
+        let results = collector
+            .collect_batched(
+                instance_queries(3, 1),
+                Utc::now() - Duration::minutes(5),
+                Utc::now(),
+            )
+            .await
+            .unwrap();
+
+        let summary: Vec<(&str, &str, Vec<f64>)> = results
+            .iter()
+            .map(|r| {
+                (
+                    r.query.dimension_value.as_str(),
+                    r.status_label(),
+                    r.values.clone(),
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                ("db-0", "PartialData", vec![1.0]),
+                ("db-1", "Missing", vec![]),
+                ("db-2", "Complete", vec![7.0]),
+            ]
+        );
+        assert!(results.iter().all(|r| r.stat == "Average"));
+    }
+
+    #[test]
+    fn test_latest_datapoint_by_timestamp() {
+        let at = |minute: u32| {
+            DateTime::parse_from_rfc3339(&format!("2025-01-01T00:0{}:00Z", minute))
+                .unwrap()
+                .with_timezone(&Utc)
+        };
+        let result = MetricQueryResult {
+            query: MetricQuery::new("AWS/RDS", "CPUUtilization", "DBInstanceIdentifier", "db"),
+            stat: "Average".to_string(),
+            timestamps: vec![at(3), at(1), at(2)],
+            values: vec![30.0, 10.0, 20.0],
+            status: Some(StatusCode::Complete),
+        };
+
+        assert_eq!(result.latest(), Some((at(3), 30.0)));
+    }
+
+    #[test]
+    fn test_statistic_validation() {
+        for stat in [
+            "Average",
+            "SampleCount",
+            "p99",
+            "p99.9",
+            "tm99",
+            "TM(10%:90%)",
+            "IQM",
+        ] {
+            assert!(is_valid_statistic(stat), "{}", stat);
+        }
+        for stat in ["average", "Median", "p", "px", "tm(10%"] {
+            assert!(!is_valid_statistic(stat), "{}", stat);
+        }
+    }
+
     #[tokio::test]
     async fn test_invalid_parameters() {
-        let client = create_test_client();
+        let client = create_test_client(&ReplayConnector::default());
         let config = MetricConfig::default();
-        let mut collector = CloudWatchCollector::new(client, config);
+        let collector = CloudWatchCollector::new(client, config);
 
         let result = collector
-            .collect_all_metrics(
-                vec![(
+            .collect_batched(
+                vec![MetricQuery::new(
                     "",
                     "CPUUtilization",
                     "DBInstanceIdentifier",
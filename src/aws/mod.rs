@@ -2,3 +2,6 @@
 
 pub mod cloudwatch;
 pub mod rds;
+
+#[cfg(test)]
+pub mod test_util;
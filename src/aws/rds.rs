@@ -1,12 +1,15 @@
-use aws_sdk_rds::types::{DbInstance, Tag};
+use crate::exporter_metrics::{AWS_API_CALLS, AWS_API_RETRIES};
+use crate::glob::glob_match;
+use aws_sdk_rds::types::{DbCluster, DbInstance, Filter, Tag};
 use aws_sdk_rds::Client;
 use aws_smithy_runtime_api::client::result::SdkError;
 use aws_smithy_runtime_api::http::Response;
+use regex::Regex;
 use std::collections::HashMap;
 use std::time::SystemTime;
 use thiserror::Error;
 use tokio::time::{sleep, Duration};
-use tracing::{debug, error, info, warn};
+use tracing::{debug, info, warn};
 
 #[derive(Error, Debug)]
 pub enum RdsError {
@@ -45,8 +48,8 @@ pub struct RdsConfig {
     pub retry_delay: Duration,
     pub cache_ttl: Duration,
     pub page_size: i32,
-    pub target_tag_key: String,
-    pub target_tag_value: String,
+    /// 수집 대상 선택 식
+    pub target_filter: TagFilter,
 }
 
 impl Default for RdsConfig {
@@ -56,47 +59,273 @@ impl Default for RdsConfig {
             retry_delay: Duration::from_secs(1),
             cache_ttl: Duration::from_secs(300),
             page_size: 100,
-            target_tag_key: "env".to_string(),
-            target_tag_value: "prd".to_string(),
+            target_filter: TagFilter::new("env", "prd"),
         }
     }
 }
 
+/// 대상 선택 식
+///
+/// 태그 키 비교는 대소문자를 무시합니다. `Equals`의 값 비교도 대소문자를 무시하고,
+/// `Matches`는 정규식 그대로(`(?i)`로 대소문자 무시 가능) 비교합니다.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct TagFilter {
-    pub key: String,
-    pub value: String,
+pub enum TagFilter {
+    /// 태그 값이 목록 중 하나와 같음
+    Equals { key: String, values: Vec<String> },
+    /// 태그 값이 정규식과 일치
+    Matches { key: String, pattern: TagPattern },
+    /// 태그 키가 존재함
+    Exists { key: String },
+    /// 엔진이 글롭 패턴 중 하나와 일치
+    Engine(Vec<String>),
+    /// 인스턴스 클래스가 글롭 패턴 중 하나와 일치
+    Class(Vec<String>),
+    /// 식별자가 접두사 중 하나로 시작함
+    IdentifierPrefix(Vec<String>),
+    /// 클러스터 식별자가 목록 중 하나와 같음 (인스턴스는 소속 클러스터 기준)
+    ClusterId(Vec<String>),
+    /// 모든 조건을 만족
+    All(Vec<TagFilter>),
+    /// 조건 중 하나 이상을 만족
+    Any(Vec<TagFilter>),
+    /// 조건을 만족하지 않음
+    Not(Box<TagFilter>),
+}
+
+/// 태그 값 정규식. 캐시 키로 쓰기 위해 패턴 문자열로 비교합니다.
+#[derive(Debug, Clone)]
+pub struct TagPattern(Regex);
+
+impl TagPattern {
+    pub fn new(pattern: &str) -> std::result::Result<Self, regex::Error> {
+        Regex::new(pattern).map(Self)
+    }
+
+    pub fn as_str(&self) -> &str {
+        self.0.as_str()
+    }
+}
+
+impl PartialEq for TagPattern {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_str() == other.as_str()
+    }
+}
+
+impl Eq for TagPattern {}
+
+impl std::hash::Hash for TagPattern {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.as_str().hash(state);
+    }
+}
+
+/// 필터를 평가할 인스턴스 또는 클러스터의 속성
+#[derive(Debug, Clone, Copy)]
+pub struct FilterSubject<'a> {
+    pub identifier: &'a str,
+    pub engine: &'a str,
+    /// 클래스가 없는 클러스터(Aurora 등)는 빈 문자열
+    pub class: &'a str,
+    /// 클러스터 식별자. 클러스터에 속하지 않은 인스턴스는 빈 문자열
+    pub cluster_id: &'a str,
+    pub tags: &'a [Tag],
+}
+
+impl<'a> FilterSubject<'a> {
+    fn instance(instance: &'a DbInstance, tags: &'a [Tag]) -> Self {
+        Self {
+            identifier: instance.db_instance_identifier().unwrap_or_default(),
+            engine: instance.engine().unwrap_or_default(),
+            class: instance.db_instance_class().unwrap_or_default(),
+            cluster_id: instance.db_cluster_identifier().unwrap_or_default(),
+            tags,
+        }
+    }
+
+    fn cluster(cluster: &'a DbCluster, tags: &'a [Tag]) -> Self {
+        Self {
+            identifier: cluster.db_cluster_identifier().unwrap_or_default(),
+            engine: cluster.engine().unwrap_or_default(),
+            class: cluster.db_cluster_instance_class().unwrap_or_default(),
+            cluster_id: cluster.db_cluster_identifier().unwrap_or_default(),
+            tags,
+        }
+    }
+
+    fn tag_value(&self, key: &str) -> Option<&'a str> {
+        self.tags
+            .iter()
+            .find(|tag| tag.key().is_some_and(|k| k.eq_ignore_ascii_case(key)))
+            .map(|tag| tag.value().unwrap_or_default())
+    }
 }
 
 impl TagFilter {
+    /// 태그 하나의 값이 같은지 비교하는 필터 (`target.tag_key`/`tag_value`)
     pub fn new(key: impl Into<String>, value: impl Into<String>) -> Self {
-        Self {
+        TagFilter::Equals {
             key: key.into(),
-            value: value.into(),
+            values: vec![value.into()],
         }
     }
 
-    fn matches(&self, tags: &[Tag]) -> bool {
-        tags.iter().any(|tag| {
-            tag.key()
-                .map_or(false, |k| k.eq_ignore_ascii_case(&self.key))
-                && tag
-                    .value()
-                    .map_or(false, |v| v.eq_ignore_ascii_case(&self.value))
+    pub fn matches(&self, subject: &FilterSubject) -> bool {
+        match self {
+            TagFilter::Equals { key, values } => subject
+                .tag_value(key)
+                .is_some_and(|v| values.iter().any(|value| value.eq_ignore_ascii_case(v))),
+            TagFilter::Matches { key, pattern } => subject
+                .tag_value(key)
+                .is_some_and(|v| pattern.0.is_match(v)),
+            TagFilter::Exists { key } => subject.tag_value(key).is_some(),
+            TagFilter::Engine(patterns) => patterns
+                .iter()
+                .any(|pattern| glob_match(pattern, subject.engine)),
+            TagFilter::Class(patterns) => patterns
+                .iter()
+                .any(|pattern| glob_match(pattern, subject.class)),
+            TagFilter::IdentifierPrefix(prefixes) => prefixes
+                .iter()
+                .any(|prefix| subject.identifier.starts_with(prefix.as_str())),
+            TagFilter::ClusterId(ids) => ids.iter().any(|id| id == subject.cluster_id),
+            TagFilter::All(filters) => filters.iter().all(|filter| filter.matches(subject)),
+            TagFilter::Any(filters) => filters.iter().any(|filter| filter.matches(subject)),
+            TagFilter::Not(filter) => !filter.matches(subject),
+        }
+    }
+
+    /// Describe 요청에 붙일 서버 측 `Filters`를 만듭니다.
+    ///
+    /// 식과 일치하려면 반드시 만족해야 하는 engine, db-cluster-id 조건만 옮기므로
+    /// 서버가 돌려준 결과에도 식을 다시 평가해야 합니다.
+    pub fn server_filters(filters: &[TagFilter]) -> Vec<Filter> {
+        let engines = |filter: &TagFilter| match filter {
+            // 글롭 패턴은 서버 필터로 표현할 수 없습니다
+            TagFilter::Engine(patterns) if patterns.iter().all(|p| !p.contains(['*', '?'])) => {
+                Some(patterns.clone())
+            }
+            _ => None,
+        };
+        let cluster_ids = |filter: &TagFilter| match filter {
+            TagFilter::ClusterId(ids) => Some(ids.clone()),
+            _ => None,
+        };
+
+        [
+            ("engine", required_values(filters, &engines)),
+            ("db-cluster-id", required_values(filters, &cluster_ids)),
+        ]
+        .into_iter()
+        .filter_map(|(name, values)| {
+            values.map(|values| {
+                Filter::builder()
+                    .name(name)
+                    .set_values(Some(values))
+                    .build()
+            })
         })
+        .collect()
+    }
+
+    /// 식과 일치하는 대상이 가질 수 있는 값 목록. 제약이 없으면 `None`
+    fn required_values(
+        &self,
+        leaf: &impl Fn(&TagFilter) -> Option<Vec<String>>,
+    ) -> Option<Vec<String>> {
+        match self {
+            TagFilter::All(filters) => required_values(filters, leaf),
+            TagFilter::Any(filters) => {
+                let mut values = Vec::new();
+                for filter in filters {
+                    for value in filter.required_values(leaf)? {
+                        if !values.contains(&value) {
+                            values.push(value);
+                        }
+                    }
+                }
+                Some(values)
+            }
+            // 부정 조건은 서버 필터로 옮기지 않습니다
+            TagFilter::Not(_) => None,
+            filter => leaf(filter),
+        }
+    }
+}
+
+/// 모든 필터를 만족하는 대상이 가질 수 있는 값 목록 (각 필터 제약의 교집합)
+fn required_values(
+    filters: &[TagFilter],
+    leaf: &impl Fn(&TagFilter) -> Option<Vec<String>>,
+) -> Option<Vec<String>> {
+    filters
+        .iter()
+        .filter_map(|filter| filter.required_values(leaf))
+        .reduce(|acc, values| acc.into_iter().filter(|v| values.contains(v)).collect())
+}
+
+impl std::fmt::Display for TagFilter {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let join = |filters: &[TagFilter]| {
+            filters
+                .iter()
+                .map(ToString::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        };
+
+        match self {
+            TagFilter::Equals { key, values } => write!(f, "{} in [{}]", key, values.join(", ")),
+            TagFilter::Matches { key, pattern } => write!(f, "{} =~ /{}/", key, pattern.as_str()),
+            TagFilter::Exists { key } => write!(f, "{} exists", key),
+            TagFilter::Engine(patterns) => write!(f, "engine in [{}]", patterns.join(", ")),
+            TagFilter::Class(patterns) => write!(f, "class in [{}]", patterns.join(", ")),
+            TagFilter::IdentifierPrefix(prefixes) => {
+                write!(f, "identifier_prefix in [{}]", prefixes.join(", "))
+            }
+            TagFilter::ClusterId(ids) => write!(f, "cluster_id in [{}]", ids.join(", ")),
+            TagFilter::All(filters) => write!(f, "all({})", join(filters)),
+            TagFilter::Any(filters) => write!(f, "any({})", join(filters)),
+            TagFilter::Not(filter) => write!(f, "not({})", filter),
+        }
     }
 }
 
 #[derive(Debug)]
-struct CacheEntry {
-    instances: Vec<DbInstance>,
+struct CacheEntry<T> {
+    items: Vec<T>,
     timestamp: SystemTime,
 }
 
+impl<T: Clone> CacheEntry<T> {
+    fn new(items: Vec<T>) -> Self {
+        Self {
+            items,
+            timestamp: SystemTime::now(),
+        }
+    }
+
+    /// TTL 이내의 항목이면 복사본을 반환합니다.
+    fn fresh_items(&self, ttl: Duration) -> Option<Vec<T>> {
+        match self.timestamp.elapsed() {
+            Ok(elapsed) if elapsed < ttl => Some(self.items.clone()),
+            Ok(_) => {
+                debug!("캐시 만료");
+                None
+            }
+            Err(e) => {
+                warn!("캐시 타임스탬프 확인 실패: {}", e);
+                None
+            }
+        }
+    }
+}
+
 pub struct RdsInstanceManager {
     client: Client,
     config: RdsConfig,
-    cache: HashMap<Vec<TagFilter>, CacheEntry>,
+    cache: HashMap<Vec<TagFilter>, CacheEntry<DbInstance>>,
+    cluster_cache: HashMap<Vec<TagFilter>, CacheEntry<DbCluster>>,
 }
 
 impl RdsInstanceManager {
@@ -105,51 +334,97 @@ impl RdsInstanceManager {
             client,
             config,
             cache: HashMap::new(),
+            cluster_cache: HashMap::new(),
         }
     }
 
+    /// 대상 선택 식을 바꿉니다. 식이 달라지면 캐시를 비워 다음 조회에서 다시 불러옵니다.
+    pub fn set_target_filter(&mut self, filter: TagFilter) {
+        if self.config.target_filter == filter {
+            return;
+        }
+
+        info!(
+            "대상 필터 변경: {} -> {}",
+            self.config.target_filter, filter
+        );
+        self.config.target_filter = filter;
+        self.cache.clear();
+        self.cluster_cache.clear();
+    }
+
+    fn target_filters(&self) -> Vec<TagFilter> {
+        vec![self.config.target_filter.clone()]
+    }
+
     pub async fn get_prd_instances(&mut self) -> Result<Vec<DbInstance>> {
-        let filters = vec![TagFilter::new(
-            self.config.target_tag_key.clone(),
-            self.config.target_tag_value.clone(),
-        )];
+        let filters = self.target_filters();
         self.get_instances_by_tags(filters).await
     }
 
+    pub async fn get_prd_clusters(&mut self) -> Result<Vec<DbCluster>> {
+        let filters = self.target_filters();
+        self.get_clusters_by_tags(filters).await
+    }
+
     /// 특정 태그를 가진 RDS 인스턴스들을 조회합니다.
     pub async fn get_instances_by_tags(
         &mut self,
         filters: Vec<TagFilter>,
     ) -> Result<Vec<DbInstance>> {
-        if let Some(entry) = self.cache.get(&filters) {
-            match entry.timestamp.elapsed() {
-                Ok(elapsed) if elapsed < self.config.cache_ttl => {
-                    debug!("캐시된 인스턴스 정보 반환");
-                    return Ok(entry.instances.clone());
-                }
-                Ok(_) => debug!("캐시 만료"),
-                Err(e) => warn!("캐시 타임스탬프 확인 실패: {}", e),
-            }
+        if let Some(instances) = self
+            .cache
+            .get(&filters)
+            .and_then(|entry| entry.fresh_items(self.config.cache_ttl))
+        {
+            debug!("캐시된 인스턴스 정보 반환");
+            return Ok(instances);
         }
 
         let instances = self.fetch_filtered_instances(filters.clone()).await?;
 
         // 캐시 업데이트
-        self.cache.insert(
-            filters,
-            CacheEntry {
-                instances: instances.clone(),
-                timestamp: SystemTime::now(),
-            },
-        );
+        self.cache
+            .insert(filters, CacheEntry::new(instances.clone()));
 
         Ok(instances)
     }
 
+    /// 특정 태그를 가진 DB 클러스터(Aurora 등)들을 조회합니다.
+    pub async fn get_clusters_by_tags(
+        &mut self,
+        filters: Vec<TagFilter>,
+    ) -> Result<Vec<DbCluster>> {
+        if let Some(clusters) = self
+            .cluster_cache
+            .get(&filters)
+            .and_then(|entry| entry.fresh_items(self.config.cache_ttl))
+        {
+            debug!("캐시된 클러스터 정보 반환");
+            return Ok(clusters);
+        }
+
+        let clusters = self.fetch_filtered_clusters(filters.clone()).await?;
+
+        self.cluster_cache
+            .insert(filters, CacheEntry::new(clusters.clone()));
+
+        Ok(clusters)
+    }
+
     async fn fetch_filtered_instances(&self, filters: Vec<TagFilter>) -> Result<Vec<DbInstance>> {
         let mut filtered_instances = Vec::new();
         let mut next_token = None;
 
+        let server_filters = TagFilter::server_filters(&filters);
+        if server_filters
+            .iter()
+            .any(|filter| filter.values().is_empty())
+        {
+            debug!("대상 필터와 일치할 수 있는 값이 없어 조회를 건너뜁니다");
+            return Ok(filtered_instances);
+        }
+
         loop {
             let mut req = self.client.describe_db_instances();
 
@@ -157,18 +432,26 @@ impl RdsInstanceManager {
                 req = req.marker(token);
             }
 
-            req = req.max_records(self.config.page_size);
+            req = req
+                .max_records(self.config.page_size)
+                .set_filters((!server_filters.is_empty()).then(|| server_filters.clone()));
 
             let response = self
-                .call_with_retry(|| async { req.clone().send().await.map_err(RdsError::from) })
+                .call_with_retry("DescribeDBInstances", || async {
+                    req.clone().send().await.map_err(RdsError::from)
+                })
                 .await?;
 
             for instance in response.db_instances() {
                 if let Some(arn) = instance.db_instance_arn() {
-                    match self.get_instance_tags(arn).await {
+                    match self.resource_tags(instance.tag_list.as_deref(), arn).await {
                         Ok(tags) => {
-                            if filters.iter().all(|filter| filter.matches(&tags)) {
-                                filtered_instances.push(instance.clone());
+                            let subject = FilterSubject::instance(instance, &tags);
+                            if filters.iter().all(|filter| filter.matches(&subject)) {
+                                // 조회한 태그를 그대로 보관하여 레이블 생성에 사용합니다
+                                let mut instance = instance.clone();
+                                instance.tag_list = Some(tags);
+                                filtered_instances.push(instance);
                             }
                         }
                         Err(e) => {
@@ -188,9 +471,76 @@ impl RdsInstanceManager {
         Ok(filtered_instances)
     }
 
-    async fn get_instance_tags(&self, arn: &str) -> Result<Vec<Tag>> {
+    async fn fetch_filtered_clusters(&self, filters: Vec<TagFilter>) -> Result<Vec<DbCluster>> {
+        let mut filtered_clusters = Vec::new();
+        let mut next_token = None;
+
+        let server_filters = TagFilter::server_filters(&filters);
+        if server_filters
+            .iter()
+            .any(|filter| filter.values().is_empty())
+        {
+            debug!("대상 필터와 일치할 수 있는 값이 없어 조회를 건너뜁니다");
+            return Ok(filtered_clusters);
+        }
+
+        loop {
+            let mut req = self.client.describe_db_clusters();
+
+            if let Some(token) = next_token.as_ref() {
+                req = req.marker(token);
+            }
+
+            req = req
+                .max_records(self.config.page_size)
+                .set_filters((!server_filters.is_empty()).then(|| server_filters.clone()));
+
+            let response = self
+                .call_with_retry("DescribeDBClusters", || async {
+                    req.clone().send().await.map_err(RdsError::from)
+                })
+                .await?;
+
+            for cluster in response.db_clusters() {
+                if let Some(arn) = cluster.db_cluster_arn() {
+                    match self.resource_tags(cluster.tag_list.as_deref(), arn).await {
+                        Ok(tags) => {
+                            let subject = FilterSubject::cluster(cluster, &tags);
+                            if filters.iter().all(|filter| filter.matches(&subject)) {
+                                // 조회한 태그를 그대로 보관하여 레이블 생성에 사용합니다
+                                let mut cluster = cluster.clone();
+                                cluster.tag_list = Some(tags);
+                                filtered_clusters.push(cluster);
+                            }
+                        }
+                        Err(e) => {
+                            warn!("클러스터 {} 태그 조회 실패: {:?}", arn, e);
+                            continue;
+                        }
+                    }
+                }
+            }
+
+            next_token = response.marker().map(String::from);
+            if next_token.is_none() {
+                break;
+            }
+        }
+
+        Ok(filtered_clusters)
+    }
+
+    /// Describe 응답에 포함된 태그를 사용하고, 없을 때만 ListTagsForResource로 조회합니다.
+    async fn resource_tags(&self, embedded: Option<&[Tag]>, arn: &str) -> Result<Vec<Tag>> {
+        match embedded {
+            Some(tags) => Ok(tags.to_vec()),
+            None => self.get_resource_tags(arn).await,
+        }
+    }
+
+    async fn get_resource_tags(&self, arn: &str) -> Result<Vec<Tag>> {
         let response = self
-            .call_with_retry(|| async {
+            .call_with_retry("ListTagsForResource", || async {
                 self.client
                     .list_tags_for_resource()
                     .resource_name(arn)
@@ -205,7 +555,7 @@ impl RdsInstanceManager {
         Ok(response.tag_list().to_vec())
     }
 
-    async fn call_with_retry<F, Fut, T>(&self, f: F) -> Result<T>
+    async fn call_with_retry<F, Fut, T>(&self, operation: &str, f: F) -> Result<T>
     where
         F: Fn() -> Fut,
         Fut: std::future::Future<Output = Result<T>>,
@@ -215,15 +565,30 @@ impl RdsInstanceManager {
         let mut delay = self.config.retry_delay;
 
         while attempts < self.config.max_retries {
+            if attempts > 0 {
+                AWS_API_RETRIES.with_label_values(&["rds", operation]).inc();
+            }
+
             match f().await {
                 Ok(response) => {
+                    AWS_API_CALLS
+                        .with_label_values(&["rds", operation, "success"])
+                        .inc();
                     if attempts > 0 {
                         info!("재시도 성공 (시도 횟수: {})", attempts + 1);
                     }
                     return Ok(response);
                 }
                 Err(e) => {
-                    warn!("API 호출 실패 (시도 횟수: {}): {:?}", attempts + 1, e);
+                    AWS_API_CALLS
+                        .with_label_values(&["rds", operation, "error"])
+                        .inc();
+                    warn!(
+                        "{} 호출 실패 (시도 횟수: {}): {:?}",
+                        operation,
+                        attempts + 1,
+                        e
+                    );
                     last_error = Some(e);
                     attempts += 1;
 
@@ -244,31 +609,259 @@ impl RdsInstanceManager {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use aws_sdk_rds::config::Config;
-    use aws_smithy_types::test_connection::TestConnection;
+    use crate::aws::test_util::ReplayConnector;
+    use aws_sdk_rds::config::{Config, Credentials, Region};
 
-    fn create_test_client() -> Client {
-        let conn = TestConnection::new(Vec::new());
+    const EMPTY_INSTANCES: &str = r#"<DescribeDBInstancesResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <DescribeDBInstancesResult><DBInstances/></DescribeDBInstancesResult>
+</DescribeDBInstancesResponse>"#;
+
+    fn create_test_client(connector: &ReplayConnector) -> Client {
         let conf = Config::builder()
             .behavior_version_latest()
-            .connection_provider(conn)
+            .region(Region::new("ap-northeast-2"))
+            .credentials_provider(Credentials::new("test", "test", None, None, "test"))
+            .http_client(connector.http_client())
             .build();
         Client::from_conf(conf)
     }
 
+    fn test_config() -> RdsConfig {
+        RdsConfig {
+            retry_delay: Duration::from_millis(1),
+            ..Default::default()
+        }
+    }
+
     #[tokio::test]
     async fn test_tag_filter_matching() {
         let filter = TagFilter::new("env", "prd");
         let tags = vec![Tag::builder().key("env").value("prd").build()];
+        let subject = FilterSubject {
+            identifier: "orders-1",
+            engine: "mysql",
+            class: "db.r6g.large",
+            cluster_id: "",
+            tags: &tags,
+        };
+
+        assert!(filter.matches(&subject));
+    }
 
-        assert!(filter.matches(&tags));
+    #[test]
+    fn test_tag_filter_expressions() {
+        let tags = vec![
+            Tag::builder().key("Env").value("PROD").build(),
+            Tag::builder().key("team").value("payments").build(),
+            Tag::builder().key("lifecycle").value("temp-2024").build(),
+        ];
+        let subject = FilterSubject {
+            identifier: "orders-1",
+            engine: "aurora-mysql",
+            class: "db.r6g.large",
+            cluster_id: "",
+            tags: &tags,
+        };
+        let pattern = |p: &str| TagPattern::new(p).unwrap();
+
+        assert!(TagFilter::Equals {
+            key: "env".to_string(),
+            values: vec!["prd".to_string(), "prod".to_string()],
+        }
+        .matches(&subject));
+        assert!(TagFilter::Matches {
+            key: "team".to_string(),
+            pattern: pattern("^pay"),
+        }
+        .matches(&subject));
+        assert!(!TagFilter::Matches {
+            key: "team".to_string(),
+            pattern: pattern("^PAY"),
+        }
+        .matches(&subject));
+        assert!(TagFilter::Exists {
+            key: "TEAM".to_string()
+        }
+        .matches(&subject));
+        assert!(!TagFilter::Exists {
+            key: "owner".to_string()
+        }
+        .matches(&subject));
+        assert!(TagFilter::Engine(vec!["aurora-*".to_string()]).matches(&subject));
+        assert!(!TagFilter::Class(vec!["db.t3.*".to_string()]).matches(&subject));
+        assert!(TagFilter::IdentifierPrefix(vec!["orders-".to_string()]).matches(&subject));
+
+        let expression = TagFilter::All(vec![
+            TagFilter::new("env", "prod"),
+            TagFilter::Not(Box::new(TagFilter::Matches {
+                key: "lifecycle".to_string(),
+                pattern: pattern("^temp-"),
+            })),
+        ]);
+        assert!(!expression.matches(&subject));
+
+        let expression = TagFilter::Any(vec![
+            TagFilter::Class(vec!["db.t3.*".to_string()]),
+            TagFilter::IdentifierPrefix(vec!["orders-".to_string()]),
+        ]);
+        assert!(expression.matches(&subject));
+        assert_eq!(
+            expression.to_string(),
+            "any(class in [db.t3.*], identifier_prefix in [orders-])"
+        );
+    }
+
+    #[test]
+    fn test_server_filters_from_expression() {
+        let filter = |name: &str, values: &[&str]| {
+            Filter::builder()
+                .name(name)
+                .set_values(Some(values.iter().map(|v| v.to_string()).collect()))
+                .build()
+        };
+        let engine =
+            |engines: &[&str]| TagFilter::Engine(engines.iter().map(|e| e.to_string()).collect());
+
+        assert!(TagFilter::server_filters(&[TagFilter::new("env", "prd")]).is_empty());
+        assert_eq!(
+            TagFilter::server_filters(&[TagFilter::All(vec![
+                TagFilter::new("env", "prd"),
+                engine(&["mysql", "postgres"]),
+                TagFilter::ClusterId(vec!["orders".to_string()]),
+            ])]),
+            vec![
+                filter("engine", &["mysql", "postgres"]),
+                filter("db-cluster-id", &["orders"]),
+            ]
+        );
+        // 여러 조건의 교집합과 합집합
+        assert_eq!(
+            TagFilter::server_filters(&[
+                engine(&["mysql", "postgres"]),
+                TagFilter::Any(vec![engine(&["postgres"]), engine(&["mariadb"])]),
+            ]),
+            vec![filter("engine", &["postgres"])]
+        );
+        // 글롭, 부정, 제약 없는 분기는 서버 필터로 옮기지 않습니다
+        assert!(TagFilter::server_filters(&[engine(&["aurora-*"])]).is_empty());
+        assert!(
+            TagFilter::server_filters(&[TagFilter::Not(Box::new(engine(&["mysql"])))]).is_empty()
+        );
+        assert!(TagFilter::server_filters(&[TagFilter::Any(vec![
+            engine(&["mysql"]),
+            TagFilter::new("env", "prd"),
+        ])])
+        .is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_instance_discovery_uses_embedded_tags() {
+        let instances = r#"<DescribeDBInstancesResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <DescribeDBInstancesResult>
+    <DBInstances>
+      <DBInstance>
+        <DBInstanceIdentifier>orders-1</DBInstanceIdentifier>
+        <DBInstanceArn>arn:aws:rds:ap-northeast-2:123456789012:db:orders-1</DBInstanceArn>
+        <Engine>mysql</Engine>
+        <TagList><Tag><Key>env</Key><Value>prd</Value></Tag></TagList>
+      </DBInstance>
+      <DBInstance>
+        <DBInstanceIdentifier>orders-dev</DBInstanceIdentifier>
+        <DBInstanceArn>arn:aws:rds:ap-northeast-2:123456789012:db:orders-dev</DBInstanceArn>
+        <Engine>mysql</Engine>
+        <TagList><Tag><Key>env</Key><Value>dev</Value></Tag></TagList>
+      </DBInstance>
+      <DBInstance>
+        <DBInstanceIdentifier>legacy</DBInstanceIdentifier>
+        <DBInstanceArn>arn:aws:rds:ap-northeast-2:123456789012:db:legacy</DBInstanceArn>
+        <Engine>mysql</Engine>
+      </DBInstance>
+    </DBInstances>
+  </DescribeDBInstancesResult>
+</DescribeDBInstancesResponse>"#;
+        let legacy_tags = r#"<ListTagsForResourceResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <ListTagsForResourceResult><TagList><Tag><Key>env</Key><Value>prd</Value></Tag></TagList></ListTagsForResourceResult>
+</ListTagsForResourceResponse>"#;
+        let connector = ReplayConnector::new([instances, legacy_tags]);
+        let mut manager = RdsInstanceManager::new(
+            create_test_client(&connector),
+            RdsConfig {
+                target_filter: TagFilter::All(vec![
+                    TagFilter::new("env", "prd"),
+                    TagFilter::Engine(vec!["mysql".to_string()]),
+                ]),
+                ..test_config()
+            },
+        );
+
+        let result = manager.get_prd_instances().await.unwrap();
+
+        let identifiers: Vec<_> = result
+            .iter()
+            .filter_map(|instance| instance.db_instance_identifier())
+            .collect();
+        assert_eq!(identifiers, vec!["orders-1", "legacy"]);
+        assert_eq!(result[1].tag_list().len(), 1);
+
+        // 태그 목록이 없는 인스턴스에 대해서만 ListTagsForResource를 호출합니다
+        let requests = connector.requests();
+        assert_eq!(requests.len(), 2);
+        assert!(requests[0].contains("Filters.Filter.1.Name=engine"));
+        assert!(requests[0].contains("Filters.Filter.1.Values.Value.1=mysql"));
+        assert!(requests[1].contains("Action=ListTagsForResource"));
     }
 
     #[tokio::test]
     async fn test_multiple_tag_filters() {
-        let mut manager = RdsInstanceManager::new(create_test_client(), RdsConfig::default());
+        let connector = ReplayConnector::new([EMPTY_INSTANCES]);
+        let mut manager = RdsInstanceManager::new(create_test_client(&connector), test_config());
         let filters = vec![TagFilter::new("env", "prd")];
         let result = manager.get_instances_by_tags(filters).await;
         assert!(result.is_ok());
     }
+
+    #[tokio::test]
+    async fn test_cluster_discovery_filters_by_tags() {
+        let clusters = r#"<DescribeDBClustersResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <DescribeDBClustersResult>
+    <DBClusters>
+      <DBCluster>
+        <DBClusterIdentifier>orders</DBClusterIdentifier>
+        <DBClusterArn>arn:aws:rds:ap-northeast-2:123456789012:cluster:orders</DBClusterArn>
+        <Engine>aurora-mysql</Engine>
+      </DBCluster>
+      <DBCluster>
+        <DBClusterIdentifier>sandbox</DBClusterIdentifier>
+        <DBClusterArn>arn:aws:rds:ap-northeast-2:123456789012:cluster:sandbox</DBClusterArn>
+        <Engine>aurora-postgresql</Engine>
+      </DBCluster>
+    </DBClusters>
+  </DescribeDBClustersResult>
+</DescribeDBClustersResponse>"#;
+        let tags = |value: &str| {
+            format!(
+                r#"<ListTagsForResourceResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <ListTagsForResourceResult><TagList><Tag><Key>env</Key><Value>{}</Value></Tag></TagList></ListTagsForResourceResult>
+</ListTagsForResourceResponse>"#,
+                value
+            )
+        };
+        let connector = ReplayConnector::new([clusters.to_string(), tags("prd"), tags("dev")]);
+        let mut manager = RdsInstanceManager::new(create_test_client(&connector), test_config());
+        let result = manager.get_prd_clusters().await.unwrap();
+
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].db_cluster_identifier(), Some("orders"));
+
+        // 두 번째 호출은 캐시에서 반환되어야 합니다
+        let cached = manager.get_prd_clusters().await.unwrap();
+        assert_eq!(cached.len(), 1);
+        assert_eq!(connector.requests().len(), 3);
+        assert!(
+            AWS_API_CALLS
+                .with_label_values(&["rds", "ListTagsForResource", "success"])
+                .get()
+                >= 2
+        );
+    }
 }
@@ -0,0 +1,68 @@
+// src/aws/test_util.rs
+use aws_smithy_runtime_api::client::http::{
+    http_client_fn, HttpConnector, HttpConnectorFuture, SharedHttpClient, SharedHttpConnector,
+};
+use aws_smithy_runtime_api::client::orchestrator::{HttpRequest, HttpResponse};
+use aws_smithy_runtime_api::client::result::ConnectorError;
+use aws_smithy_runtime_api::http::StatusCode;
+use aws_smithy_types::body::SdkBody;
+use std::collections::VecDeque;
+use std::sync::{Arc, Mutex};
+
+/// 미리 준비한 응답 본문을 순서대로 돌려주는 테스트용 HTTP 커넥터
+#[derive(Debug, Clone, Default)]
+pub struct ReplayConnector {
+    /// `None`은 응답하지 않고 멈추는 요청
+    responses: Arc<Mutex<VecDeque<Option<String>>>>,
+    requests: Arc<Mutex<Vec<String>>>,
+}
+
+impl ReplayConnector {
+    pub fn new<S: Into<String>>(responses: impl IntoIterator<Item = S>) -> Self {
+        Self {
+            responses: Arc::new(Mutex::new(
+                responses.into_iter().map(|r| Some(r.into())).collect(),
+            )),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// 첫 요청은 응답 없이 멈춘 뒤 준비한 응답을 순서대로 돌려줍니다.
+    pub fn stalling_first(self) -> Self {
+        self.responses.lock().unwrap().push_front(None);
+        self
+    }
+
+    /// 지금까지 수신한 요청 본문 목록
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    pub fn http_client(&self) -> SharedHttpClient {
+        let connector = self.clone();
+        http_client_fn(move |_, _| SharedHttpConnector::new(connector.clone()))
+    }
+}
+
+impl HttpConnector for ReplayConnector {
+    fn call(&self, request: HttpRequest) -> HttpConnectorFuture {
+        let body = request
+            .body()
+            .bytes()
+            .map(|b| String::from_utf8_lossy(b).into_owned())
+            .unwrap_or_default();
+        self.requests.lock().unwrap().push(body);
+
+        let next = self.responses.lock().unwrap().pop_front();
+        HttpConnectorFuture::new(async move {
+            match next {
+                Some(None) => std::future::pending().await,
+                Some(Some(body)) => Ok(HttpResponse::new(
+                    StatusCode::try_from(200).unwrap(),
+                    SdkBody::from(body),
+                )),
+                None => Err(ConnectorError::other("준비된 응답이 없습니다".into(), None)),
+            }
+        })
+    }
+}
@@ -1,6 +1,11 @@
 // src/config.rs
-use config::{Config, ConfigError, Environment, File};
+use crate::aws::cloudwatch::{is_valid_statistic, MetricConfig};
+use crate::aws::rds::{TagFilter, TagPattern};
+use crate::labels::{CLUSTER_LABELS, INSTANCE_LABELS};
+use aws_sdk_cloudwatch::types::StandardUnit;
+use config::{Config, ConfigError, Environment, File, FileFormat};
 use serde::Deserialize;
+use std::collections::BTreeMap;
 use std::env;
 
 #[derive(Debug, Deserialize)]
@@ -9,30 +14,251 @@ pub struct Settings {
     pub exporter: ExporterSettings,
     pub target: TargetSettings,
     pub cloudwatch: CloudWatchSettings,
+    #[serde(default)]
+    pub metrics: MetricsSettings,
+    #[serde(default)]
+    pub tag_labels: TagLabelSettings,
+    #[serde(default)]
+    pub publishers: PublishersSettings,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize)]
 pub struct AwsSettings {
-    pub region: String,
+    /// 단일 리전 (`regions`가 비어 있을 때 사용)
+    #[serde(default)]
+    pub region: Option<String>,
+    /// 수집할 리전 목록
+    #[serde(default)]
+    pub regions: Vec<String>,
     pub credentials: Option<AwsCredentials>,
+    /// AssumeRole로 수집할 계정 목록. 비어 있으면 기본 자격 증명의 계정만 수집합니다.
+    #[serde(default)]
+    pub accounts: Vec<AccountSettings>,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct AccountSettings {
+    /// `arn:aws:iam::<계정 ID>:role/<역할 이름>`
+    pub role_arn: String,
+    pub external_id: Option<String>,
+    /// 생략 시 `rds-exporter`
+    pub session_name: Option<String>,
+    /// 생략 시 `aws.region(s)`
+    #[serde(default)]
+    pub regions: Vec<String>,
+}
+
+impl AccountSettings {
+    /// 역할 ARN의 계정 ID 부분
+    pub fn account_id(&self) -> Option<&str> {
+        let mut parts = self.role_arn.splitn(6, ':');
+        match (
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+            parts.next(),
+        ) {
+            (Some("arn"), Some(_), Some("iam"), Some(""), Some(account_id), Some(resource))
+                if account_id.len() == 12
+                    && account_id.chars().all(|c| c.is_ascii_digit())
+                    && resource.starts_with("role/") =>
+            {
+                Some(account_id)
+            }
+            _ => None,
+        }
+    }
+
+    pub fn session_name(&self) -> &str {
+        self.session_name.as_deref().unwrap_or("rds-exporter")
+    }
+}
+
+/// 처음 나온 순서를 유지하며 중복 리전을 제거합니다.
+fn dedup_regions(regions: &[String]) -> Vec<String> {
+    let mut deduped: Vec<String> = Vec::new();
+    for region in regions {
+        if !deduped.contains(region) {
+            deduped.push(region.clone());
+        }
+    }
+    deduped
+}
+
+impl AwsSettings {
+    /// 중복을 제거한 수집 대상 리전 목록. `regions`가 비어 있으면 `region`을 사용합니다.
+    pub fn regions(&self) -> Vec<String> {
+        let regions = if self.regions.is_empty() {
+            self.region.as_slice()
+        } else {
+            self.regions.as_slice()
+        };
+        dedup_regions(regions)
+    }
+
+    /// 중복을 제거한 계정의 수집 대상 리전 목록. 계정에 `regions`가 없으면 [`regions`](Self::regions)를 사용합니다.
+    pub fn account_regions(&self, account: &AccountSettings) -> Vec<String> {
+        if account.regions.is_empty() {
+            self.regions()
+        } else {
+            dedup_regions(&account.regions)
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let regions = self.regions();
+        if regions.is_empty() {
+            return Err("aws.region 또는 aws.regions를 지정해야 합니다".to_string());
+        }
+        if regions.iter().any(|region| region.trim().is_empty()) {
+            return Err("aws.regions에 빈 리전이 있습니다".to_string());
+        }
+        for account in &self.accounts {
+            if account.account_id().is_none() {
+                return Err(format!(
+                    "aws.accounts: 잘못된 역할 ARN: {}",
+                    account.role_arn
+                ));
+            }
+            if account
+                .regions
+                .iter()
+                .any(|region| region.trim().is_empty())
+            {
+                return Err(format!(
+                    "aws.accounts ({}): 빈 리전이 있습니다",
+                    account.role_arn
+                ));
+            }
+        }
+        Ok(())
+    }
+}
+
+#[derive(Debug, PartialEq, Deserialize)]
 pub struct AwsCredentials {
     pub profile: String,
 }
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, PartialEq, Deserialize)]
 pub struct ExporterSettings {
     pub host: String,
     pub port: u16,
     pub collection_interval: u64,
+    /// 대상 인스턴스/클러스터 조회 주기 (초). 수집 주기와 별개로 백그라운드에서 실행
+    #[serde(default = "default_discovery_interval")]
+    pub discovery_interval: u64,
+    /// CloudWatch 데이터 포인트 시각을 /metrics에 함께 노출할지 여부
+    #[serde(default)]
+    pub export_timestamps: bool,
+    /// 통계 표현 방식: `label`(stat 레이블) 또는 `suffix`(메트릭 이름 접미사)
+    #[serde(default)]
+    pub stat_style: StatStyle,
+    /// 이 주기 수 동안 갱신되지 않은 시계열을 제거 (기본 3, 0이면 제거하지 않음)
+    #[serde(default = "default_stale_series_cycles")]
+    pub stale_series_cycles: u64,
+    /// 마지막 성공 후 이 주기 수가 지나면 /ready가 503을 반환
+    #[serde(default = "default_ready_stale_cycles")]
+    pub ready_stale_cycles: u64,
+    /// 종료 신호 후 진행 중인 수집과 HTTP 요청을 기다리는 최대 시간 (초)
+    #[serde(default = "default_shutdown_grace_period")]
+    pub shutdown_grace_period: u64,
+}
+
+/// CloudWatch 통계를 Prometheus 메트릭에 표현하는 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StatStyle {
+    /// `rds_cpuutilization{stat="p99"}`
+    #[default]
+    Label,
+    /// `rds_cpuutilization_p99`
+    Suffix,
+}
+
+impl ExporterSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.collection_interval == 0 {
+            return Err("exporter.collection_interval은 1 이상이어야 합니다".to_string());
+        }
+        if self.discovery_interval == 0 {
+            return Err("exporter.discovery_interval은 1 이상이어야 합니다".to_string());
+        }
+        if self.ready_stale_cycles == 0 {
+            return Err("exporter.ready_stale_cycles는 1 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+fn default_discovery_interval() -> u64 {
+    300
+}
+
+fn default_shutdown_grace_period() -> u64 {
+    30
+}
+
+fn default_stale_series_cycles() -> u64 {
+    3
+}
+
+fn default_ready_stale_cycles() -> u64 {
+    3
 }
 
 #[derive(Debug, Deserialize)]
 pub struct TargetSettings {
-    pub tag_key: String,
-    pub tag_value: String,
+    /// 대상 태그 키 (`filter`가 없을 때 사용)
+    #[serde(default)]
+    pub tag_key: Option<String>,
+    /// 대상 태그 값 (`filter`가 없을 때 사용)
+    #[serde(default)]
+    pub tag_value: Option<String>,
+    /// 대상 선택 식. 지정하면 `tag_key`/`tag_value`보다 우선합니다
+    #[serde(default)]
+    pub filter: Option<TargetFilterSettings>,
+}
+
+/// 설정 파일의 대상 선택 식. 각 항목은 키 하나를 가진 맵입니다.
+///
+/// ```yaml
+/// filter:
+///   all:
+///     - tag: { key: env, values: [prd, prod] }
+///     - not: { tag: { key: lifecycle, regex: "^temp-" } }
+///     - any:
+///         - engine: ["aurora-*"]
+///         - identifier_prefix: [orders-]
+/// ```
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TargetFilterSettings {
+    All(Vec<TargetFilterSettings>),
+    Any(Vec<TargetFilterSettings>),
+    Not(Box<TargetFilterSettings>),
+    Tag(TagConditionSettings),
+    Engine(Vec<String>),
+    Class(Vec<String>),
+    IdentifierPrefix(Vec<String>),
+    ClusterId(Vec<String>),
+}
+
+/// 태그 조건. `values`와 `regex`를 모두 생략하면 키 존재 여부만 확인합니다.
+#[derive(Debug, Clone, Deserialize)]
+pub struct TagConditionSettings {
+    pub key: String,
+    /// 허용할 값 목록 (대소문자 무시)
+    #[serde(default)]
+    pub values: Vec<String>,
+    /// 값 정규식
+    #[serde(default)]
+    pub regex: Option<String>,
+    /// `false`이면 키가 없는 대상만 선택
+    #[serde(default)]
+    pub exists: Option<bool>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -41,6 +267,790 @@ pub struct CloudWatchSettings {
     pub stat: String,
     pub retry_attempts: u32,
     pub retry_delay: u64,
+    /// 동시에 실행할 GetMetricData 요청 수. 요청 하나에는 여러 대상의 쿼리가 최대 500개까지 묶입니다
+    #[serde(default = "default_max_concurrent_requests")]
+    pub max_concurrent_requests: usize,
+    /// GetMetricData 호출 한 번의 제한 시간 (초). 페이지와 재시도마다 따로 적용됩니다
+    #[serde(default = "default_request_timeout")]
+    pub request_timeout: u64,
+    /// 대상 하나의 데이터를 조회하는 전체 제한 시간 (초). 넘기면 그 대상이 포함된 배치만 이번 주기에서 빠집니다
+    #[serde(default = "default_target_timeout")]
+    pub target_timeout: u64,
+}
+
+fn default_max_concurrent_requests() -> usize {
+    4
+}
+
+fn default_request_timeout() -> u64 {
+    30
+}
+
+fn default_target_timeout() -> u64 {
+    120
+}
+
+impl CloudWatchSettings {
+    pub fn metric_config(&self) -> MetricConfig {
+        MetricConfig {
+            period: self.period,
+            stat: self.stat.clone(),
+            retry_attempts: self.retry_attempts,
+            retry_delay: chrono::Duration::seconds(self.retry_delay as i64),
+            max_concurrent_requests: self.max_concurrent_requests,
+            request_timeout: chrono::Duration::seconds(self.request_timeout as i64),
+            target_timeout: chrono::Duration::seconds(self.target_timeout as i64),
+        }
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        if self.max_concurrent_requests == 0 {
+            return Err("cloudwatch.max_concurrent_requests는 1 이상이어야 합니다".to_string());
+        }
+        if self.request_timeout == 0 {
+            return Err("cloudwatch.request_timeout은 1 이상이어야 합니다".to_string());
+        }
+        if self.target_timeout == 0 {
+            return Err("cloudwatch.target_timeout은 1 이상이어야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// 엔진별 수집 메트릭 카탈로그. 설정에서 생략하면 `config/default.yaml`의 카탈로그를 사용합니다.
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricsSettings {
+    /// `DBInstanceIdentifier` 차원으로 조회할 메트릭 세트
+    #[serde(default)]
+    pub instance: Vec<MetricSetSettings>,
+    /// `DBClusterIdentifier` 차원으로 조회할 메트릭 세트
+    #[serde(default)]
+    pub cluster: Vec<MetricSetSettings>,
+}
+
+/// 엔진 이름 패턴(`*`, `?` 글롭)과 일치하는 대상에 적용되는 메트릭 목록
+#[derive(Debug, Clone, Deserialize)]
+pub struct MetricSetSettings {
+    pub engines: Vec<String>,
+    pub metrics: Vec<MetricSettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct MetricSettings {
+    pub name: String,
+    /// 수집할 통계 목록 (Average, Maximum, p99, tm99 등). 생략 시 `cloudwatch.stat`
+    #[serde(default)]
+    pub stats: Vec<String>,
+    /// 생략 시 `cloudwatch.period`
+    pub period: Option<i32>,
+    /// CloudWatch에 저장된 단위와 일치해야 데이터가 반환됩니다
+    pub unit: Option<String>,
+}
+
+/// `/metrics` 외에 추가로 사용할 푸시 방식 발행자
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct PublishersSettings {
+    #[serde(default)]
+    pub remote_write: Option<RemoteWriteSettings>,
+    #[serde(default)]
+    pub otlp: Option<OtlpSettings>,
+    #[serde(default)]
+    pub influxdb: Option<InfluxDbSettings>,
+    #[serde(default)]
+    pub statsd: Option<StatsdSettings>,
+    #[serde(default)]
+    pub pushgateway: Option<PushgatewaySettings>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct RemoteWriteSettings {
+    /// remote write 수신 주소 (예: `http://prometheus:9090/api/v1/write`)
+    pub url: String,
+    /// 요청 하나에 담을 최대 시계열 수
+    #[serde(default = "default_remote_write_batch_size")]
+    pub batch_size: usize,
+    /// 전송을 기다리는 샘플의 최대 수
+    #[serde(default = "default_remote_write_queue_capacity")]
+    pub queue_capacity: usize,
+    #[serde(flatten)]
+    pub http: HttpPublisherSettings,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct OtlpSettings {
+    /// gRPC는 collector 주소 (예: `http://otel-collector:4317`),
+    /// HTTP는 전체 경로 (예: `http://otel-collector:4318/v1/metrics`)
+    pub endpoint: String,
+    #[serde(default)]
+    pub protocol: OtlpProtocol,
+    /// 요청 하나에 담을 최대 데이터 포인트 수
+    #[serde(default = "default_otlp_batch_size")]
+    pub batch_size: usize,
+    /// 전송을 기다리는 데이터 포인트의 최대 수
+    #[serde(default = "default_otlp_queue_capacity")]
+    pub queue_capacity: usize,
+    /// 모든 요청에 붙일 헤더 (gRPC는 메타데이터)
+    #[serde(default)]
+    pub headers: BTreeMap<String, String>,
+    #[serde(flatten)]
+    pub http: HttpPublisherSettings,
+}
+
+/// OTLP 전송 방식 (`OTEL_EXPORTER_OTLP_PROTOCOL`과 같은 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum OtlpProtocol {
+    /// `MetricsService/Export` gRPC 호출 (보통 4317 포트)
+    #[default]
+    #[serde(rename = "grpc")]
+    Grpc,
+    /// protobuf 본문을 HTTP POST로 전송 (보통 `:4318/v1/metrics`)
+    #[serde(rename = "http/protobuf")]
+    HttpProtobuf,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct InfluxDbSettings {
+    /// v2 write API 서버 주소 (예: `http://influxdb:8086`) 또는 UDP 수신기 주소 (예: `udp://influxdb:8089`)
+    pub url: String,
+    /// HTTP 전용
+    #[serde(default)]
+    pub org: Option<String>,
+    /// HTTP 전용, 필수
+    #[serde(default)]
+    pub bucket: Option<String>,
+    /// InfluxDB API 토큰 (HTTP 전용)
+    #[serde(default)]
+    pub token: Option<String>,
+    #[serde(default)]
+    pub precision: InfluxPrecision,
+    /// 요청 하나에 담을 최대 줄 수
+    #[serde(default = "default_influxdb_batch_size")]
+    pub batch_size: usize,
+    /// 전송을 기다리는 줄의 최대 수
+    #[serde(default = "default_influxdb_queue_capacity")]
+    pub queue_capacity: usize,
+    /// UDP 패킷 하나의 최대 크기 (바이트)
+    #[serde(default = "default_max_packet_size")]
+    pub max_packet_size: usize,
+    #[serde(flatten)]
+    pub http: HttpPublisherSettings,
+}
+
+/// line protocol 타임스탬프 단위 (write API의 `precision` 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum InfluxPrecision {
+    #[default]
+    Ns,
+    Us,
+    Ms,
+    S,
+}
+
+impl InfluxPrecision {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            InfluxPrecision::Ns => "ns",
+            InfluxPrecision::Us => "us",
+            InfluxPrecision::Ms => "ms",
+            InfluxPrecision::S => "s",
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct StatsdSettings {
+    /// StatsD 서버 주소 (예: `statsd:8125`)
+    pub address: String,
+    /// 모든 메트릭 이름 앞에 붙일 접두사
+    #[serde(default)]
+    pub prefix: String,
+    /// 레이블을 DogStatsD 태그로 보낼지 여부
+    #[serde(default)]
+    pub dogstatsd: bool,
+    /// UDP 패킷 하나의 최대 크기 (바이트)
+    #[serde(default = "default_max_packet_size")]
+    pub max_packet_size: usize,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct PushgatewaySettings {
+    /// Pushgateway 주소 (예: `http://pushgateway:9091`)
+    pub url: String,
+    #[serde(default = "default_pushgateway_job")]
+    pub job: String,
+    #[serde(default)]
+    pub method: PushMethod,
+    /// 모든 그룹에 붙는 고정 그룹 레이블 (예: `instance: prod`)
+    #[serde(default)]
+    pub grouping_key: BTreeMap<String, String>,
+    /// 값별로 그룹을 나눌 메트릭 레이블 (예: `[region, account_id]`)
+    #[serde(default)]
+    pub group_by: Vec<String>,
+    /// 정상 종료할 때 푸시한 그룹을 삭제할지 여부
+    #[serde(default = "default_pushgateway_delete_on_shutdown")]
+    pub delete_on_shutdown: bool,
+    #[serde(flatten)]
+    pub http: HttpPublisherSettings,
+}
+
+/// 그룹에 푸시하는 방식
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PushMethod {
+    /// 그룹의 메트릭 전체를 교체합니다
+    #[default]
+    Put,
+    /// 같은 이름의 메트릭만 교체합니다
+    Post,
+}
+
+/// HTTP로 전송하는 발행자의 공통 설정
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct HttpPublisherSettings {
+    /// 최대 시도 횟수
+    #[serde(default = "default_publisher_max_retries")]
+    pub max_retries: u32,
+    /// 첫 재시도 전 대기 시간 (초)
+    #[serde(default = "default_publisher_retry_delay")]
+    pub retry_delay: u64,
+    /// 요청 제한 시간 (초)
+    #[serde(default = "default_publisher_timeout")]
+    pub timeout: u64,
+    #[serde(default)]
+    pub basic_auth: Option<BasicAuthSettings>,
+    #[serde(default)]
+    pub bearer_token: Option<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct BasicAuthSettings {
+    pub username: String,
+    pub password: String,
+}
+
+fn default_remote_write_batch_size() -> usize {
+    500
+}
+
+fn default_remote_write_queue_capacity() -> usize {
+    10000
+}
+
+fn default_otlp_batch_size() -> usize {
+    1000
+}
+
+fn default_otlp_queue_capacity() -> usize {
+    10000
+}
+
+fn default_influxdb_batch_size() -> usize {
+    5000
+}
+
+fn default_influxdb_queue_capacity() -> usize {
+    50000
+}
+
+/// 일반적인 이더넷 MTU에서 IP/UDP 헤더를 뺀 크기
+fn default_max_packet_size() -> usize {
+    1432
+}
+
+fn default_pushgateway_job() -> String {
+    "rds_exporter".to_string()
+}
+
+fn default_pushgateway_delete_on_shutdown() -> bool {
+    true
+}
+
+fn default_publisher_max_retries() -> u32 {
+    3
+}
+
+fn default_publisher_retry_delay() -> u64 {
+    1
+}
+
+fn default_publisher_timeout() -> u64 {
+    30
+}
+
+#[cfg(test)]
+impl PublishersSettings {
+    /// 다섯 발행기를 모두 켠 테스트용 `publishers` 섹션 (전체 검증을 통과합니다)
+    pub fn example() -> Self {
+        let yaml = r#"
+remote_write:
+  url: http://prometheus:9090/api/v1/write
+  batch_size: 200
+  timeout: 10
+  basic_auth:
+    username: rds
+    password: secret
+otlp:
+  endpoint: http://otel-collector:4318/v1/metrics
+  protocol: http/protobuf
+  headers:
+    X-Scope-OrgID: dba
+  bearer_token: secret
+influxdb:
+  url: http://influxdb:8086/
+  org: dba team
+  bucket: rds
+  token: secret
+  precision: ms
+statsd:
+  address: datadog-agent:8125
+  prefix: dba
+  dogstatsd: true
+pushgateway:
+  url: http://pushgateway:9091
+  method: post
+  grouping_key:
+    instance: prod
+  group_by: [region, account_id]
+"#;
+        let publishers: Self = Config::builder()
+            .add_source(File::from_str(yaml, FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+        publishers.validate().unwrap();
+        publishers
+    }
+}
+
+impl PublishersSettings {
+    fn validate(&self) -> Result<(), String> {
+        if let Some(remote_write) = &self.remote_write {
+            remote_write
+                .validate()
+                .map_err(|e| format!("publishers.remote_write: {}", e))?;
+        }
+        if let Some(otlp) = &self.otlp {
+            otlp.validate()
+                .map_err(|e| format!("publishers.otlp: {}", e))?;
+        }
+        if let Some(influxdb) = &self.influxdb {
+            influxdb
+                .validate()
+                .map_err(|e| format!("publishers.influxdb: {}", e))?;
+        }
+        if let Some(statsd) = &self.statsd {
+            statsd
+                .validate()
+                .map_err(|e| format!("publishers.statsd: {}", e))?;
+        }
+        if let Some(pushgateway) = &self.pushgateway {
+            pushgateway
+                .validate()
+                .map_err(|e| format!("publishers.pushgateway: {}", e))?;
+        }
+        Ok(())
+    }
+}
+
+impl RemoteWriteSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err("batch_size는 0보다 커야 합니다".to_string());
+        }
+        if self.queue_capacity < self.batch_size {
+            return Err("queue_capacity는 batch_size 이상이어야 합니다".to_string());
+        }
+        self.http.validate(&self.url)
+    }
+}
+
+impl OtlpSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err("batch_size는 0보다 커야 합니다".to_string());
+        }
+        if self.queue_capacity < self.batch_size {
+            return Err("queue_capacity는 batch_size 이상이어야 합니다".to_string());
+        }
+        for (name, value) in &self.headers {
+            if reqwest::header::HeaderName::from_bytes(name.as_bytes()).is_err()
+                || reqwest::header::HeaderValue::from_str(value).is_err()
+            {
+                return Err(format!("잘못된 헤더입니다: {}", name));
+            }
+        }
+        self.http.validate(&self.endpoint)
+    }
+}
+
+impl InfluxDbSettings {
+    /// `udp://` 주소면 `호스트:포트`
+    pub fn udp_address(&self) -> Option<&str> {
+        self.url.strip_prefix("udp://")
+    }
+
+    fn validate(&self) -> Result<(), String> {
+        if self.batch_size == 0 {
+            return Err("batch_size는 0보다 커야 합니다".to_string());
+        }
+        if self.queue_capacity < self.batch_size {
+            return Err("queue_capacity는 batch_size 이상이어야 합니다".to_string());
+        }
+        if let Some(address) = self.udp_address() {
+            if address.is_empty() {
+                return Err("udp:// 뒤에 호스트:포트가 필요합니다".to_string());
+            }
+            if self.max_packet_size == 0 {
+                return Err("max_packet_size는 0보다 커야 합니다".to_string());
+            }
+            return Ok(());
+        }
+
+        if self.bucket.as_deref().unwrap_or_default().is_empty() {
+            return Err("HTTP로 기록하려면 bucket이 필요합니다".to_string());
+        }
+        if self.token.is_some()
+            && (self.http.basic_auth.is_some() || self.http.bearer_token.is_some())
+        {
+            return Err("token은 basic_auth, bearer_token과 함께 사용할 수 없습니다".to_string());
+        }
+        self.http.validate(&self.url)?;
+        self.write_url().map(|_| ())
+    }
+
+    /// org, bucket, precision 쿼리를 붙인 `/api/v2/write` 주소
+    pub fn write_url(&self) -> Result<String, String> {
+        let mut params = vec![("precision", self.precision.as_str())];
+        if let Some(bucket) = &self.bucket {
+            params.push(("bucket", bucket));
+        }
+        if let Some(org) = &self.org {
+            params.push(("org", org));
+        }
+        let url = format!("{}/api/v2/write", self.url.trim_end_matches('/'));
+        reqwest::Url::parse_with_params(&url, &params)
+            .map(String::from)
+            .map_err(|e| format!("잘못된 url입니다 ({}): {}", self.url, e))
+    }
+}
+
+impl StatsdSettings {
+    fn validate(&self) -> Result<(), String> {
+        match self.address.rsplit_once(':') {
+            Some((host, port)) if !host.is_empty() && port.parse::<u16>().is_ok() => {}
+            _ => {
+                return Err(format!(
+                    "address는 호스트:포트 형식이어야 합니다: {}",
+                    self.address
+                ))
+            }
+        }
+        if self.max_packet_size == 0 {
+            return Err("max_packet_size는 0보다 커야 합니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+impl PushgatewaySettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.job.is_empty() {
+            return Err("job이 비어 있습니다".to_string());
+        }
+        let mut seen: Vec<&str> = Vec::new();
+        for name in self.grouping_key.keys().chain(&self.group_by) {
+            if name.is_empty() || sanitize_label_name(name) != *name || name.starts_with("__") {
+                return Err(format!("잘못된 그룹 레이블 이름: {}", name));
+            }
+            if name == "job" || seen.contains(&name.as_str()) {
+                return Err(format!("중복된 그룹 레이블 이름: {}", name));
+            }
+            seen.push(name);
+        }
+        self.http.validate(&self.url)
+    }
+}
+
+impl HttpPublisherSettings {
+    fn validate(&self, url: &str) -> Result<(), String> {
+        if !(url.starts_with("http://") || url.starts_with("https://")) {
+            return Err(format!(
+                "url은 http:// 또는 https://로 시작해야 합니다: {}",
+                url
+            ));
+        }
+        if self.max_retries == 0 {
+            return Err("max_retries는 0보다 커야 합니다".to_string());
+        }
+        if self.timeout == 0 {
+            return Err("timeout은 0보다 커야 합니다".to_string());
+        }
+        if self.basic_auth.is_some() && self.bearer_token.is_some() {
+            return Err("basic_auth와 bearer_token은 함께 사용할 수 없습니다".to_string());
+        }
+        Ok(())
+    }
+}
+
+/// Prometheus 레이블로 내보낼 AWS 리소스 태그 목록
+#[derive(Debug, Clone, Default, PartialEq, Deserialize)]
+pub struct TagLabelSettings {
+    #[serde(default)]
+    pub mode: TagLabelMode,
+    #[serde(default)]
+    pub keys: Vec<TagLabelKey>,
+}
+
+/// 태그 레이블을 붙이는 위치
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum TagLabelMode {
+    /// 모든 메트릭에 레이블로 추가
+    #[default]
+    Labels,
+    /// `rds_instance_tags` / `rds_cluster_tags` 정보 메트릭에만 추가
+    Info,
+}
+
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct TagLabelKey {
+    /// AWS 태그 키 (대소문자 구분)
+    pub key: String,
+    /// 레이블 이름. 생략 시 `tag_<키>`
+    pub label: Option<String>,
+}
+
+impl TagLabelKey {
+    /// Prometheus 레이블 이름 규칙(`[a-zA-Z_][a-zA-Z0-9_]*`)에 맞게 변환한 레이블 이름
+    pub fn label_name(&self) -> String {
+        match &self.label {
+            Some(label) => sanitize_label_name(label),
+            None => sanitize_label_name(&format!("tag_{}", self.key)),
+        }
+    }
+}
+
+impl TagLabelSettings {
+    pub fn label_names(&self) -> Vec<String> {
+        self.keys.iter().map(TagLabelKey::label_name).collect()
+    }
+
+    pub fn validate(&self) -> Result<(), String> {
+        let mut seen: Vec<String> = Vec::new();
+
+        for key in &self.keys {
+            if key.key.is_empty() {
+                return Err("tag_labels.keys: 태그 키가 비어 있습니다".to_string());
+            }
+            let name = key.label_name();
+            if name.starts_with("__") {
+                return Err(format!(
+                    "tag_labels ({}): 예약된 레이블 이름: {}",
+                    key.key, name
+                ));
+            }
+            if name == "stat"
+                || INSTANCE_LABELS.contains(&name.as_str())
+                || CLUSTER_LABELS.contains(&name.as_str())
+            {
+                return Err(format!(
+                    "tag_labels ({}): 기본 레이블과 겹치는 이름: {}",
+                    key.key, name
+                ));
+            }
+            if seen.contains(&name) {
+                return Err(format!(
+                    "tag_labels ({}): 중복된 레이블 이름: {}",
+                    key.key, name
+                ));
+            }
+            seen.push(name);
+        }
+
+        Ok(())
+    }
+}
+
+/// 허용되지 않는 문자를 `_`로 바꾸고, 숫자로 시작하면 `_`를 앞에 붙입니다.
+fn sanitize_label_name(name: &str) -> String {
+    let mut sanitized: String = name
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    if sanitized.is_empty() || sanitized.starts_with(|c: char| c.is_ascii_digit()) {
+        sanitized.insert(0, '_');
+    }
+    sanitized
+}
+
+/// 바이너리에 포함한 기본 설정 파일 (`metrics` 기본값의 원본)
+const DEFAULT_CONFIG: &str = include_str!("../config/default.yaml");
+
+impl Default for MetricsSettings {
+    fn default() -> Self {
+        Config::builder()
+            .add_source(File::from_str(DEFAULT_CONFIG, FileFormat::Yaml))
+            .build()
+            .and_then(|config| config.get("metrics"))
+            .expect("config/default.yaml의 metrics 카탈로그를 읽을 수 없습니다")
+    }
+}
+
+impl MetricsSettings {
+    pub fn validate(&self) -> Result<(), String> {
+        if self.instance.is_empty() && self.cluster.is_empty() {
+            return Err("metrics.instance와 metrics.cluster가 모두 비어 있습니다".to_string());
+        }
+
+        for (section, sets) in [("instance", &self.instance), ("cluster", &self.cluster)] {
+            for (idx, set) in sets.iter().enumerate() {
+                let location = format!("metrics.{}[{}]", section, idx);
+
+                if set.engines.is_empty() || set.engines.iter().any(|e| e.trim().is_empty()) {
+                    return Err(format!("{}: engines 패턴이 비어 있습니다", location));
+                }
+
+                for metric in &set.metrics {
+                    metric
+                        .validate()
+                        .map_err(|e| format!("{} ({}): {}", location, metric.name, e))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl MetricSettings {
+    fn validate(&self) -> Result<(), String> {
+        if self.name.is_empty() || !self.name.chars().all(|c| c.is_ascii_alphanumeric()) {
+            return Err("메트릭 이름은 영문자와 숫자로만 구성되어야 합니다".to_string());
+        }
+        for (idx, stat) in self.stats.iter().enumerate() {
+            if !is_valid_statistic(stat) {
+                return Err(format!("지원하지 않는 통계: {}", stat));
+            }
+            if self.stats[..idx].contains(stat) {
+                return Err(format!("중복된 통계: {}", stat));
+            }
+        }
+        if let Some(period) = self.period {
+            // GetMetricData는 1, 5, 10, 20, 30초(고해상도) 또는 60의 배수만 허용합니다
+            if period <= 0 || !(matches!(period, 1 | 5 | 10 | 20 | 30) || period % 60 == 0) {
+                return Err(format!("잘못된 period: {}", period));
+            }
+        }
+        if let Some(unit) = &self.unit {
+            if !StandardUnit::values().contains(&unit.as_str()) {
+                return Err(format!("알 수 없는 단위: {}", unit));
+            }
+        }
+        Ok(())
+    }
+}
+
+impl TargetSettings {
+    /// 설정을 평가 가능한 필터로 변환합니다. 설정이 잘못되었으면 오류를 반환합니다.
+    pub fn tag_filter(&self) -> Result<TagFilter, String> {
+        if let Some(filter) = &self.filter {
+            return filter.to_tag_filter("target.filter");
+        }
+
+        match (&self.tag_key, &self.tag_value) {
+            (Some(key), Some(value)) if !key.is_empty() => Ok(TagFilter::new(key, value)),
+            _ => Err("target.filter 또는 target.tag_key/tag_value를 지정해야 합니다".to_string()),
+        }
+    }
+}
+
+impl TargetFilterSettings {
+    fn to_tag_filter(&self, location: &str) -> Result<TagFilter, String> {
+        let non_empty = |name: &str, items: &[String]| {
+            if items.is_empty() || items.iter().any(|item| item.is_empty()) {
+                Err(format!(
+                    "{}.{}: 빈 목록이나 빈 값은 사용할 수 없습니다",
+                    location, name
+                ))
+            } else {
+                Ok(items.to_vec())
+            }
+        };
+        let children = |name: &str, filters: &[TargetFilterSettings]| {
+            if filters.is_empty() {
+                return Err(format!("{}.{}: 조건이 비어 있습니다", location, name));
+            }
+            filters
+                .iter()
+                .enumerate()
+                .map(|(idx, filter)| {
+                    filter.to_tag_filter(&format!("{}.{}[{}]", location, name, idx))
+                })
+                .collect::<Result<Vec<_>, _>>()
+        };
+
+        match self {
+            TargetFilterSettings::All(filters) => Ok(TagFilter::All(children("all", filters)?)),
+            TargetFilterSettings::Any(filters) => Ok(TagFilter::Any(children("any", filters)?)),
+            TargetFilterSettings::Not(filter) => Ok(TagFilter::Not(Box::new(
+                filter.to_tag_filter(&format!("{}.not", location))?,
+            ))),
+            TargetFilterSettings::Tag(condition) => {
+                condition.to_tag_filter(&format!("{}.tag", location))
+            }
+            TargetFilterSettings::Engine(patterns) => {
+                Ok(TagFilter::Engine(non_empty("engine", patterns)?))
+            }
+            TargetFilterSettings::Class(patterns) => {
+                Ok(TagFilter::Class(non_empty("class", patterns)?))
+            }
+            TargetFilterSettings::IdentifierPrefix(prefixes) => Ok(TagFilter::IdentifierPrefix(
+                non_empty("identifier_prefix", prefixes)?,
+            )),
+            TargetFilterSettings::ClusterId(ids) => {
+                Ok(TagFilter::ClusterId(non_empty("cluster_id", ids)?))
+            }
+        }
+    }
+}
+
+impl TagConditionSettings {
+    fn to_tag_filter(&self, location: &str) -> Result<TagFilter, String> {
+        if self.key.is_empty() {
+            return Err(format!("{}: key가 비어 있습니다", location));
+        }
+        if !self.values.is_empty() && self.regex.is_some() {
+            return Err(format!(
+                "{}: values와 regex는 함께 사용할 수 없습니다",
+                location
+            ));
+        }
+
+        let key = self.key.clone();
+        let filter = match (&self.regex, self.values.is_empty()) {
+            (Some(regex), _) => TagFilter::Matches {
+                key,
+                pattern: TagPattern::new(regex)
+                    .map_err(|e| format!("{}: 잘못된 정규식 {}: {}", location, regex, e))?,
+            },
+            (None, false) => TagFilter::Equals {
+                key,
+                values: self.values.clone(),
+            },
+            (None, true) => TagFilter::Exists { key },
+        };
+
+        match self.exists {
+            Some(false) if !matches!(filter, TagFilter::Exists { .. }) => Err(format!(
+                "{}: exists: false는 values/regex와 함께 사용할 수 없습니다",
+                location
+            )),
+            Some(false) => Ok(TagFilter::Not(Box::new(filter))),
+            _ => Ok(filter),
+        }
+    }
 }
 
 impl Settings {
@@ -56,7 +1066,47 @@ impl Settings {
             .add_source(Environment::with_prefix("APP"))
             .build()?;
 
-        s.try_deserialize()
+        let settings: Settings = s.try_deserialize()?;
+        settings.aws.validate().map_err(ConfigError::Message)?;
+        settings.exporter.validate().map_err(ConfigError::Message)?;
+        settings.target.tag_filter().map_err(ConfigError::Message)?;
+        settings
+            .cloudwatch
+            .validate()
+            .map_err(ConfigError::Message)?;
+        settings.metrics.validate().map_err(ConfigError::Message)?;
+        settings
+            .tag_labels
+            .validate()
+            .map_err(ConfigError::Message)?;
+        settings
+            .publishers
+            .validate()
+            .map_err(ConfigError::Message)?;
+
+        Ok(settings)
+    }
+}
+
+impl Settings {
+    /// 실행 중에 다시 적용할 수 없어 재시작이 필요한, `new`와 달라진 설정 섹션
+    ///
+    /// `target`, `metrics`, `cloudwatch`만 다시 읽기로 적용됩니다.
+    pub fn restart_required_changes(&self, new: &Settings) -> Vec<&'static str> {
+        let mut changed = Vec::new();
+        if self.aws != new.aws {
+            changed.push("aws");
+        }
+        if self.exporter != new.exporter {
+            changed.push("exporter");
+        }
+        if self.tag_labels != new.tag_labels {
+            changed.push("tag_labels");
+        }
+        if self.publishers != new.publishers {
+            changed.push("publishers");
+        }
+        changed
     }
 }
 
@@ -64,24 +1114,483 @@ impl Default for Settings {
     fn default() -> Self {
         Self {
             aws: AwsSettings {
-                region: "ap-northeast-2".to_string(),
+                region: Some("ap-northeast-2".to_string()),
+                regions: Vec::new(),
                 credentials: None,
+                accounts: Vec::new(),
             },
             exporter: ExporterSettings {
                 host: "0.0.0.0".to_string(),
                 port: 9043,
                 collection_interval: 60,
+                discovery_interval: default_discovery_interval(),
+                export_timestamps: false,
+                stat_style: StatStyle::Label,
+                stale_series_cycles: default_stale_series_cycles(),
+                ready_stale_cycles: default_ready_stale_cycles(),
+                shutdown_grace_period: default_shutdown_grace_period(),
             },
             target: TargetSettings {
-                tag_key: "env".to_string(),
-                tag_value: "prd".to_string(),
+                tag_key: Some("env".to_string()),
+                tag_value: Some("prd".to_string()),
+                filter: None,
             },
             cloudwatch: CloudWatchSettings {
                 period: 60,
                 stat: "Average".to_string(),
                 retry_attempts: 3,
                 retry_delay: 1,
+                max_concurrent_requests: default_max_concurrent_requests(),
+                request_timeout: default_request_timeout(),
+                target_timeout: default_target_timeout(),
             },
+            metrics: MetricsSettings::default(),
+            tag_labels: TagLabelSettings::default(),
+            publishers: PublishersSettings::default(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// AWS/RDS 네임스페이스에 실제로 존재하는 메트릭 이름
+    const KNOWN_RDS_METRICS: &[&str] = &[
+        "ActiveTransactions",
+        "AuroraGlobalDBReplicationLag",
+        "AuroraReplicaLag",
+        "BackupRetentionPeriodStorageUsed",
+        "BinLogDiskUsage",
+        "BufferCacheHitRatio",
+        "CPUUtilization",
+        "CommitLatency",
+        "DMLLatency",
+        "DatabaseConnections",
+        "Deadlocks",
+        "DiskQueueDepth",
+        "FreeLocalStorage",
+        "FreeStorageSpace",
+        "FreeableMemory",
+        "MaximumUsedTransactionIDs",
+        "NetworkReceiveThroughput",
+        "NetworkTransmitThroughput",
+        "OldestReplicationSlotLag",
+        "Queries",
+        "ReadIOPS",
+        "ReadLatency",
+        "ReadThroughput",
+        "ReplicaLag",
+        "SelectLatency",
+        "ServerlessDatabaseCapacity",
+        "SnapshotStorageUsed",
+        "SwapUsage",
+        "TotalBackupStorageBilled",
+        "TransactionLogsDiskUsage",
+        "TransactionLogsGeneration",
+        "VolumeBytesUsed",
+        "VolumeReadIOPs",
+        "VolumeWriteIOPs",
+        "WriteIOPS",
+        "WriteLatency",
+        "WriteThroughput",
+    ];
+
+    #[test]
+    fn test_default_metric_catalogue_is_valid() {
+        let metrics = MetricsSettings::default();
+        metrics.validate().unwrap();
+
+        for set in metrics.instance.iter().chain(&metrics.cluster) {
+            for metric in &set.metrics {
+                assert!(
+                    KNOWN_RDS_METRICS.contains(&metric.name.as_str()),
+                    "AWS/RDS에 없는 메트릭: {}",
+                    metric.name
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_invalid_metric_settings_are_rejected() {
+        let mut metrics = MetricsSettings::default();
+        metrics.instance[0].metrics[0].stats = vec!["Median".to_string()];
+        assert!(metrics.validate().is_err());
+
+        let mut metrics = MetricsSettings::default();
+        metrics.instance[0].metrics[0].stats = vec!["p99".to_string(), "p99".to_string()];
+        assert!(metrics.validate().is_err());
+
+        let mut metrics = MetricsSettings::default();
+        metrics.instance[0].metrics[0].period = Some(90);
+        assert!(metrics.validate().is_err());
+
+        let mut metrics = MetricsSettings::default();
+        metrics.cluster[0].metrics[0].unit = Some("Parsecs".to_string());
+        assert!(metrics.validate().is_err());
+    }
+
+    #[test]
+    fn test_invalid_intervals_are_rejected() {
+        let invalid = |update: fn(&mut Settings)| {
+            let mut settings = Settings::default();
+            update(&mut settings);
+            settings.exporter.validate().is_err() || settings.cloudwatch.validate().is_err()
+        };
+
+        assert!(!invalid(|_| {}));
+        assert!(invalid(|s| s.exporter.collection_interval = 0));
+        assert!(invalid(|s| s.exporter.discovery_interval = 0));
+        assert!(invalid(|s| s.exporter.ready_stale_cycles = 0));
+        assert!(invalid(|s| s.cloudwatch.max_concurrent_requests = 0));
+        assert!(invalid(|s| s.cloudwatch.request_timeout = 0));
+        assert!(invalid(|s| s.cloudwatch.target_timeout = 0));
+    }
+
+    #[test]
+    fn test_restart_required_changes() {
+        let current = Settings::default();
+
+        let mut new = Settings::default();
+        new.cloudwatch.period = 300;
+        new.metrics.instance.clear();
+        assert!(current.restart_required_changes(&new).is_empty());
+
+        new.aws.regions = vec!["us-east-1".to_string()];
+        new.exporter.port = 9044;
+        new.publishers.statsd = Some(StatsdSettings {
+            address: "statsd:8125".to_string(),
+            prefix: String::new(),
+            dogstatsd: false,
+            max_packet_size: default_max_packet_size(),
+        });
+        assert_eq!(
+            current.restart_required_changes(&new),
+            vec!["aws", "exporter", "publishers"]
+        );
+    }
+
+    #[test]
+    fn test_tag_label_names_are_sanitised() {
+        let key = |key: &str, label: Option<&str>| TagLabelKey {
+            key: key.to_string(),
+            label: label.map(String::from),
+        };
+
+        assert_eq!(key("team", None).label_name(), "tag_team");
+        assert_eq!(key("cost-center", None).label_name(), "tag_cost_center");
+        assert_eq!(
+            key("aws:cloudformation:stack-name", None).label_name(),
+            "tag_aws_cloudformation_stack_name"
+        );
+        assert_eq!(
+            key("cost-center", Some("cost_center")).label_name(),
+            "cost_center"
+        );
+        assert_eq!(key("tier", Some("2nd-tier")).label_name(), "_2nd_tier");
+
+        let settings = |keys: Vec<TagLabelKey>| TagLabelSettings {
+            mode: TagLabelMode::Labels,
+            keys,
+        };
+        assert!(settings(vec![
+            key("team", None),
+            key("cost-center", Some("cost_center"))
+        ])
+        .validate()
+        .is_ok());
+        // 기본 레이블, stat 레이블과 겹치거나 변환 후 중복되는 이름은 거부됩니다
+        assert!(settings(vec![key("Engine", Some("engine"))])
+            .validate()
+            .is_err());
+        assert!(settings(vec![key("stat", Some("stat"))])
+            .validate()
+            .is_err());
+        assert!(
+            settings(vec![key("cost-center", None), key("cost_center", None)])
+                .validate()
+                .is_err()
+        );
+        assert!(settings(vec![key("x", Some("__name"))]).validate().is_err());
+    }
+
+    #[test]
+    fn test_regions() {
+        let aws = |region: Option<&str>, regions: &[&str]| AwsSettings {
+            region: region.map(String::from),
+            regions: regions.iter().map(|r| r.to_string()).collect(),
+            credentials: None,
+            accounts: Vec::new(),
+        };
+
+        assert_eq!(
+            aws(Some("ap-northeast-2"), &[]).regions(),
+            vec!["ap-northeast-2"]
+        );
+        // regions가 지정되면 region은 무시되고 중복은 제거됩니다
+        assert_eq!(
+            aws(
+                Some("ap-northeast-2"),
+                &["us-east-1", "eu-west-1", "us-east-1"]
+            )
+            .regions(),
+            vec!["us-east-1", "eu-west-1"]
+        );
+        assert!(aws(None, &[]).validate().is_err());
+        assert!(aws(None, &["us-east-1", ""]).validate().is_err());
+
+        let account = |regions: &[&str]| AccountSettings {
+            role_arn: "arn:aws:iam::123456789012:role/rds-exporter".to_string(),
+            external_id: None,
+            session_name: None,
+            regions: regions.iter().map(|r| r.to_string()).collect(),
+        };
+        let settings = aws(Some("ap-northeast-2"), &[]);
+        assert_eq!(
+            settings.account_regions(&account(&[])),
+            vec!["ap-northeast-2"]
+        );
+        assert_eq!(
+            settings.account_regions(&account(&["us-east-1", "us-east-1", "eu-west-1"])),
+            vec!["us-east-1", "eu-west-1"]
+        );
+    }
+
+    #[test]
+    fn test_account_id_from_role_arn() {
+        let account = |role_arn: &str| AccountSettings {
+            role_arn: role_arn.to_string(),
+            external_id: None,
+            session_name: None,
+            regions: Vec::new(),
+        };
+
+        assert_eq!(
+            account("arn:aws:iam::123456789012:role/rds-exporter").account_id(),
+            Some("123456789012")
+        );
+        assert_eq!(
+            account("arn:aws-cn:iam::123456789012:role/path/reader").account_id(),
+            Some("123456789012")
+        );
+        assert_eq!(account("arn:aws:iam::1234:role/x").account_id(), None);
+        assert_eq!(
+            account("arn:aws:iam::123456789012:user/x").account_id(),
+            None
+        );
+        assert_eq!(account("rds-exporter").account_id(), None);
+        assert_eq!(
+            account("arn:aws:iam::123456789012:role/x").session_name(),
+            "rds-exporter"
+        );
+    }
+
+    #[test]
+    fn test_target_filter_from_yaml() {
+        let yaml = r#"
+filter:
+  all:
+    - tag: { key: env, values: [prd, prod] }
+    - tag: { key: owner }
+    - tag: { key: legacy, exists: false }
+    - not: { tag: { key: lifecycle, regex: "^temp-" } }
+    - any:
+        - engine: ["aurora-*"]
+        - class: ["db.r6g.*"]
+        - identifier_prefix: [orders-]
+    - cluster_id: [orders]
+"#;
+        let target: TargetSettings = Config::builder()
+            .add_source(File::from_str(yaml, config::FileFormat::Yaml))
+            .build()
+            .unwrap()
+            .try_deserialize()
+            .unwrap();
+
+        assert_eq!(
+            target.tag_filter().unwrap().to_string(),
+            "all(env in [prd, prod], owner exists, not(legacy exists), \
+             not(lifecycle =~ /^temp-/), \
+             any(engine in [aurora-*], class in [db.r6g.*], identifier_prefix in [orders-]), \
+             cluster_id in [orders])"
+        );
+    }
+
+    #[test]
+    fn test_invalid_target_filters_are_rejected() {
+        let target = |filter: TargetFilterSettings| TargetSettings {
+            tag_key: None,
+            tag_value: None,
+            filter: Some(filter),
+        };
+        let tag = |values: &[&str], regex: Option<&str>, exists: Option<bool>| {
+            TargetFilterSettings::Tag(TagConditionSettings {
+                key: "env".to_string(),
+                values: values.iter().map(|v| v.to_string()).collect(),
+                regex: regex.map(String::from),
+                exists,
+            })
+        };
+
+        assert_eq!(
+            Settings::default().target.tag_filter().unwrap(),
+            TagFilter::new("env", "prd")
+        );
+        assert!(TargetSettings {
+            tag_key: Some("env".to_string()),
+            tag_value: None,
+            filter: None,
+        }
+        .tag_filter()
+        .is_err());
+        assert!(target(TargetFilterSettings::All(Vec::new()))
+            .tag_filter()
+            .is_err());
+        assert!(target(TargetFilterSettings::Engine(Vec::new()))
+            .tag_filter()
+            .is_err());
+        assert!(target(tag(&["prd"], Some("^p"), None))
+            .tag_filter()
+            .is_err());
+        assert!(target(tag(&[], Some("("), None)).tag_filter().is_err());
+        assert!(target(tag(&["prd"], None, Some(false)))
+            .tag_filter()
+            .is_err());
+        assert!(
+            target(TargetFilterSettings::Any(vec![tag(&[], None, Some(false))]))
+                .tag_filter()
+                .is_ok()
+        );
+    }
+
+    #[test]
+    fn test_publisher_settings_from_yaml() {
+        let publishers = PublishersSettings::example();
+
+        let remote_write = publishers.remote_write.as_ref().unwrap();
+        assert_eq!(remote_write.batch_size, 200);
+        assert_eq!(remote_write.queue_capacity, 10000);
+        assert_eq!(remote_write.http.max_retries, 3);
+        assert_eq!(remote_write.http.timeout, 10);
+        assert!(remote_write.http.basic_auth.is_some());
+
+        let otlp = publishers.otlp.as_ref().unwrap();
+        assert_eq!(otlp.protocol, OtlpProtocol::HttpProtobuf);
+        assert_eq!(otlp.batch_size, 1000);
+        assert_eq!(otlp.headers["X-Scope-OrgID"], "dba");
+        assert_eq!(otlp.http.bearer_token.as_deref(), Some("secret"));
+
+        let influxdb = publishers.influxdb.as_ref().unwrap();
+        assert_eq!(influxdb.precision, InfluxPrecision::Ms);
+        assert_eq!(influxdb.batch_size, 5000);
+        assert_eq!(influxdb.queue_capacity, 50000);
+        assert_eq!(influxdb.udp_address(), None);
+        assert_eq!(
+            influxdb.write_url().unwrap(),
+            "http://influxdb:8086/api/v2/write?precision=ms&bucket=rds&org=dba+team"
+        );
+        let mut udp = influxdb.clone();
+        udp.url = "udp://influxdb:8089".to_string();
+        udp.bucket = None;
+        assert!(udp.validate().is_ok());
+        assert_eq!(udp.udp_address(), Some("influxdb:8089"));
+        assert_eq!(udp.max_packet_size, 1432);
+
+        let statsd = publishers.statsd.as_ref().unwrap();
+        assert_eq!(statsd.address, "datadog-agent:8125");
+        assert!(statsd.dogstatsd);
+        assert_eq!(statsd.max_packet_size, 1432);
+
+        let pushgateway = publishers.pushgateway.as_ref().unwrap();
+        assert_eq!(pushgateway.job, "rds_exporter");
+        assert_eq!(pushgateway.method, PushMethod::Post);
+        assert_eq!(pushgateway.grouping_key["instance"], "prod");
+        assert!(pushgateway.delete_on_shutdown);
+
+        type Update = fn(&mut PublishersSettings);
+        let invalid: &[(&str, Update)] = &[
+            ("remote_write.url", |p| {
+                p.remote_write.as_mut().unwrap().url = "prometheus:9090".to_string()
+            }),
+            ("remote_write.batch_size", |p| {
+                p.remote_write.as_mut().unwrap().batch_size = 0
+            }),
+            ("remote_write.queue_capacity", |p| {
+                p.remote_write.as_mut().unwrap().queue_capacity = 10
+            }),
+            ("remote_write 인증 중복", |p| {
+                p.remote_write.as_mut().unwrap().http.bearer_token = Some("token".to_string())
+            }),
+            ("otlp.endpoint", |p| {
+                p.otlp.as_mut().unwrap().endpoint = "otel-collector:4317".to_string()
+            }),
+            ("otlp.batch_size", |p| {
+                p.otlp.as_mut().unwrap().batch_size = 0
+            }),
+            ("otlp.queue_capacity", |p| {
+                p.otlp.as_mut().unwrap().queue_capacity = 10
+            }),
+            ("otlp.headers", |p| {
+                let headers = &mut p.otlp.as_mut().unwrap().headers;
+                headers.insert("bad header".to_string(), "x".to_string());
+            }),
+            ("influxdb.bucket", |p| {
+                p.influxdb.as_mut().unwrap().bucket = None
+            }),
+            ("influxdb.batch_size", |p| {
+                p.influxdb.as_mut().unwrap().batch_size = 0
+            }),
+            ("influxdb.queue_capacity", |p| {
+                p.influxdb.as_mut().unwrap().queue_capacity = 10
+            }),
+            ("influxdb.url", |p| {
+                p.influxdb.as_mut().unwrap().url = "udp://".to_string()
+            }),
+            ("influxdb 인증 중복", |p| {
+                p.influxdb.as_mut().unwrap().http.bearer_token = Some("token".to_string())
+            }),
+            ("statsd.address 포트 없음", |p| {
+                p.statsd.as_mut().unwrap().address = "datadog-agent".to_string()
+            }),
+            ("statsd.address 호스트 없음", |p| {
+                p.statsd.as_mut().unwrap().address = ":8125".to_string()
+            }),
+            ("statsd.max_packet_size", |p| {
+                p.statsd.as_mut().unwrap().max_packet_size = 0
+            }),
+            ("pushgateway.url", |p| {
+                p.pushgateway.as_mut().unwrap().url = "pushgateway:9091".to_string()
+            }),
+            ("pushgateway.job", |p| {
+                p.pushgateway.as_mut().unwrap().job = String::new()
+            }),
+            ("pushgateway.group_by job", |p| {
+                p.pushgateway
+                    .as_mut()
+                    .unwrap()
+                    .group_by
+                    .push("job".to_string())
+            }),
+            ("pushgateway.group_by grouping_key", |p| {
+                p.pushgateway
+                    .as_mut()
+                    .unwrap()
+                    .group_by
+                    .push("instance".to_string())
+            }),
+            ("pushgateway.group_by 레이블 이름", |p| {
+                p.pushgateway
+                    .as_mut()
+                    .unwrap()
+                    .group_by
+                    .push("db-id".to_string())
+            }),
+        ];
+        for (case, update) in invalid {
+            let mut settings = publishers.clone();
+            update(&mut settings);
+            assert!(settings.validate().is_err(), "{}", case);
         }
     }
 }
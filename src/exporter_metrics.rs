@@ -0,0 +1,134 @@
+// src/exporter_metrics.rs
+use lazy_static::lazy_static;
+use prometheus::{
+    register_gauge_with_registry, register_histogram_with_registry,
+    register_int_counter_vec_with_registry, register_int_counter_with_registry,
+    register_int_gauge_vec_with_registry, Gauge, Histogram, IntCounter, IntCounterVec, IntGaugeVec,
+    Registry,
+};
+
+lazy_static! {
+    /// 수집한 CloudWatch 메트릭과 exporter 자체 메트릭을 함께 담는 레지스트리
+    pub static ref REGISTRY: Registry = Registry::new();
+}
+
+// exporter 자체 상태를 나타내는 rds_exporter_* 메트릭
+lazy_static! {
+    pub static ref AWS_API_CALLS: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_aws_api_calls_total",
+        "Number of AWS API call attempts, by service, operation and outcome (success, error, timeout)",
+        &["service", "operation", "outcome"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref AWS_API_RETRIES: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_aws_api_retries_total",
+        "Number of AWS API call retries, by service and operation",
+        &["service", "operation"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref CLOUDWATCH_INCOMPLETE_RESULTS: IntCounterVec =
+        register_int_counter_vec_with_registry!(
+            "rds_exporter_cloudwatch_incomplete_results_total",
+            "Number of GetMetricData results that were not Complete, by status code",
+            &["status"],
+            REGISTRY
+        )
+        .unwrap();
+    pub static ref COLLECTION_CYCLE_DURATION: Histogram = register_histogram_with_registry!(
+        "rds_exporter_collection_cycle_duration_seconds",
+        "Time taken by one collection cycle, from querying CloudWatch to publishing",
+        vec![0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0, 300.0],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref CONFIG_LAST_RELOAD_SUCCESSFUL: Gauge = register_gauge_with_registry!(
+        "rds_exporter_config_last_reload_successful",
+        "Whether the last configuration reload attempt succeeded (1) or failed (0)",
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref CONFIG_RELOADS: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_config_reloads_total",
+        "Number of configuration reload attempts, by result (success, failure)",
+        &["result"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref DATAPOINTS_PUBLISHED: IntCounter = register_int_counter_with_registry!(
+        "rds_exporter_datapoints_published_total",
+        "Number of datapoints handed to publishers",
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref DISCOVERED_TARGETS: IntGaugeVec = register_int_gauge_vec_with_registry!(
+        "rds_exporter_discovered_targets",
+        "Number of instances and clusters matching the target filter in the last discovery",
+        &["account_id", "region", "scope"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref BATCH_COLLECTION_DURATION: Histogram = register_histogram_with_registry!(
+        "rds_exporter_batch_collection_duration_seconds",
+        "Time taken to fetch one GetMetricData batch, including all pages and retries",
+        vec![0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref TARGET_COLLECTION_DURATION: Histogram = register_histogram_with_registry!(
+        "rds_exporter_target_collection_duration_seconds",
+        "Time taken to fetch one instance's or cluster's CloudWatch data, measured as the slowest batch containing it",
+        vec![0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0, 60.0, 120.0],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref LABEL_SCHEMA_CONFLICTS: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_label_schema_conflicts_total",
+        "Number of points dropped because the metric family was registered with a different label schema",
+        &["metric"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref LAST_SUCCESSFUL_CYCLE: Gauge = register_gauge_with_registry!(
+        "rds_exporter_last_successful_cycle_timestamp_seconds",
+        "Unix time of the last collection cycle that collected CloudWatch data",
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref PUBLISHER_DROPPED_SAMPLES: IntCounterVec =
+        register_int_counter_vec_with_registry!(
+            "rds_exporter_publisher_dropped_samples_total",
+            "Number of samples dropped by push publishers, because the queue was full, sending failed or the endpoint rejected them",
+            &["publisher"],
+            REGISTRY
+        )
+        .unwrap();
+    pub static ref PUBLISHER_QUEUE_LENGTH: IntGaugeVec = register_int_gauge_vec_with_registry!(
+        "rds_exporter_publisher_queue_length",
+        "Number of samples waiting to be sent by push publishers",
+        &["publisher"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref PUBLISHER_REQUESTS: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_publisher_requests_total",
+        "Number of push publisher request attempts, by publisher and outcome (success, error, rejected)",
+        &["publisher", "outcome"],
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref SERIES_REMOVED: IntCounter = register_int_counter_with_registry!(
+        "rds_exporter_series_removed_total",
+        "Number of series removed because they were not refreshed within the stale cycle limit",
+        REGISTRY
+    )
+    .unwrap();
+    pub static ref TARGETS_CHANGED: IntCounterVec = register_int_counter_vec_with_registry!(
+        "rds_exporter_targets_changed_total",
+        "Number of instances and clusters added to or removed from the target set by discovery",
+        &["account_id", "region", "scope", "change"],
+        REGISTRY
+    )
+    .unwrap();
+}
@@ -0,0 +1,42 @@
+// src/glob.rs
+
+/// `*`(0개 이상의 문자)와 `?`(정확히 한 문자)를 지원하는 단순 글롭 매칭
+pub fn glob_match(pattern: &str, text: &str) -> bool {
+    let pattern: Vec<char> = pattern.chars().collect();
+    let text: Vec<char> = text.chars().collect();
+    let (mut p, mut t) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+
+    while t < text.len() {
+        if p < pattern.len() && (pattern[p] == '?' || pattern[p] == text[t]) {
+            p += 1;
+            t += 1;
+        } else if p < pattern.len() && pattern[p] == '*' {
+            backtrack = Some((p, t));
+            p += 1;
+        } else if let Some((star_p, star_t)) = backtrack {
+            p = star_p + 1;
+            t = star_t + 1;
+            backtrack = Some((star_p, star_t + 1));
+        } else {
+            return false;
+        }
+    }
+
+    pattern[p..].iter().all(|&c| c == '*')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_glob_match() {
+        assert!(glob_match("*", "postgres"));
+        assert!(glob_match("aurora-*", "aurora-mysql"));
+        assert!(glob_match("sqlserver-??", "sqlserver-ee"));
+        assert!(glob_match("*-mysql", "aurora-mysql"));
+        assert!(!glob_match("aurora-*", "mysql"));
+        assert!(!glob_match("mysql", "aurora-mysql"));
+    }
+}
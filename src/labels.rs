@@ -0,0 +1,23 @@
+// src/labels.rs
+
+/// 인스턴스 메트릭 레이블 (`get_instance_tags`, `account_id`, `region`은 수집 시 추가)
+pub const INSTANCE_LABELS: [&str; 7] = [
+    "account_id",
+    "availability_zone",
+    "class",
+    "db_instance_identifier",
+    "engine",
+    "engine_version",
+    "region",
+];
+
+/// 클러스터 메트릭 레이블 (`get_cluster_tags`, `account_id`, `region`은 수집 시 추가)
+pub const CLUSTER_LABELS: [&str; 7] = [
+    "account_id",
+    "db_cluster_identifier",
+    "engine",
+    "engine_version",
+    "readers",
+    "region",
+    "writer",
+];
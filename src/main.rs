@@ -1,24 +1,49 @@
 // src/main.rs
-use aws_config::BehaviorVersion;
+use aws_config::sts::AssumeRoleProvider;
+use aws_config::{BehaviorVersion, SdkConfig};
+use aws_credential_types::provider::SharedCredentialsProvider;
 use aws_sdk_cloudwatch::Client as CloudWatchClient;
 use aws_sdk_rds::Client as RdsClient;
+use aws_sdk_sts::error::DisplayErrorContext;
+use aws_sdk_sts::Client as StsClient;
 use chrono::Duration;
 use prometheus::{Encoder, TextEncoder};
 use std::convert::Infallible;
 use std::net::SocketAddr;
-use tracing::{error, info};
+use std::sync::Arc;
+use tokio::sync::watch;
+use tracing::{error, info, warn};
 use tracing_subscriber::{EnvFilter, FmtSubscriber};
+use warp::http::StatusCode;
 use warp::Filter;
 
-use crate::aws::cloudwatch::{CloudWatchCollector, MetricConfig as CWConfig};
+use crate::aws::cloudwatch::{CloudWatchCollector, MetricConfig};
 use crate::aws::rds::{RdsConfig, RdsInstanceManager};
-use crate::config::Settings;
-use crate::metrics::collector::{MetricPublisher, RdsMetricCollector};
-use crate::metrics::prometheus_publisher::PrometheusPublisher;
+use crate::config::{AccountSettings, AwsCredentials, Settings, TagLabelMode};
+use crate::metrics::catalog::MetricCatalog;
+use crate::metrics::collector::{
+    CollectionTarget, LabelSchema, MetricPublisher, RdsMetricCollector,
+};
+use crate::metrics::discovery::{DiscoveryLoop, TargetDiscovery};
+use crate::metrics::influxdb_publisher::{InfluxDbConfig, InfluxDbPublisher};
+use crate::metrics::otlp_publisher::{OtlpConfig, OtlpPublisher};
+use crate::metrics::prometheus_publisher::{PrometheusConfig, PrometheusPublisher};
+use crate::metrics::pushgateway_publisher::{PushgatewayConfig, PushgatewayPublisher};
+use crate::metrics::remote_write_publisher::{RemoteWriteConfig, RemoteWritePublisher};
+use crate::metrics::statsd_publisher::{StatsdConfig, StatsdPublisher};
+use crate::metrics::status::CollectionStatus;
+use crate::reload::ConfigReloader;
 
 mod aws;
 mod config;
+mod exporter_metrics;
+mod glob;
+mod labels;
 mod metrics;
+mod reload;
+
+/// 설정 파일 디렉터리 (`config/default.yaml`, `config/<RUN_MODE>.yaml`)
+const CONFIG_DIR: &str = "config";
 
 async fn serve_metrics(publisher: PrometheusPublisher) -> Result<impl warp::Reply, Infallible> {
     let metrics = publisher.gather();
@@ -28,8 +53,144 @@ async fn serve_metrics(publisher: PrometheusPublisher) -> Result<impl warp::Repl
     Ok(String::from_utf8(buffer).unwrap())
 }
 
-async fn serve_health() -> Result<impl warp::Reply, Infallible> {
-    Ok("OK")
+/// 리전과 프로필 설정으로 AWS SDK 설정을 불러옵니다.
+async fn load_aws_config(region: &str, credentials: Option<&AwsCredentials>) -> SdkConfig {
+    let mut aws_config_builder = aws_config::defaults(BehaviorVersion::latest())
+        .region(aws_config::Region::new(region.to_string()));
+
+    // AWS 프로필 설정이 있는 경우 적용
+    if let Some(credentials) = credentials {
+        aws_config_builder = aws_config_builder
+            .profile_name(&credentials.profile)
+            .credentials_provider(
+                aws_config::profile::ProfileFileCredentialsProvider::builder()
+                    .profile_name(&credentials.profile)
+                    .build(),
+            );
+    }
+
+    aws_config_builder.load().await
+}
+
+/// 기본 자격 증명의 계정 ID를 STS GetCallerIdentity로 조회합니다.
+/// 조회에 실패하면 수집은 계속하고 `account_id` 레이블을 비워 둡니다.
+async fn caller_account_id(aws_config: &SdkConfig) -> String {
+    match StsClient::new(aws_config)
+        .get_caller_identity()
+        .send()
+        .await
+    {
+        Ok(identity) => identity.account().unwrap_or_default().to_string(),
+        Err(e) => {
+            warn!(
+                "기본 자격 증명의 계정 ID 조회 실패, account_id 레이블을 비워 둡니다: {}",
+                DisplayErrorContext(&e)
+            );
+            String::new()
+        }
+    }
+}
+
+/// 기본 설정의 자격 증명으로 역할을 위임받는 설정을 만듭니다.
+/// 임시 자격 증명은 SDK 자격 증명 캐시가 만료 전에 자동으로 갱신합니다.
+async fn assume_role_config(base_config: &SdkConfig, account: &AccountSettings) -> SdkConfig {
+    let mut provider = AssumeRoleProvider::builder(&account.role_arn)
+        .session_name(account.session_name())
+        .configure(base_config);
+    if let Some(external_id) = &account.external_id {
+        provider = provider.external_id(external_id);
+    }
+
+    base_config
+        .to_builder()
+        .credentials_provider(SharedCredentialsProvider::new(provider.build().await))
+        .build()
+}
+
+/// 계정/리전 하나의 대상 조회기와 수집 대상을 만듭니다.
+fn collection_target(
+    aws_config: &SdkConfig,
+    account_id: &str,
+    region: String,
+    rds_config: &RdsConfig,
+    cw_config: &MetricConfig,
+) -> (TargetDiscovery, CollectionTarget) {
+    let (discovery, discovered) = TargetDiscovery::new(
+        account_id,
+        region.clone(),
+        RdsInstanceManager::new(RdsClient::new(aws_config), rds_config.clone()),
+    );
+    let target = CollectionTarget::new(
+        account_id,
+        region,
+        CloudWatchCollector::new(CloudWatchClient::new(aws_config), cw_config.clone()),
+        discovered,
+    );
+    (discovery, target)
+}
+
+/// 설정을 다시 읽고 적용 예약 여부를 반환합니다. 검증에 실패하면 500과 오류 내용을 반환합니다.
+async fn serve_reload(reloader: Arc<ConfigReloader>) -> Result<impl warp::Reply, Infallible> {
+    match reloader.reload_blocking().await {
+        Ok(()) => Ok(warp::reply::with_status("OK".to_string(), StatusCode::OK)),
+        Err(e) => Ok(warp::reply::with_status(
+            format!("설정 다시 읽기 실패: {}", e),
+            StatusCode::INTERNAL_SERVER_ERROR,
+        )),
+    }
+}
+
+/// SIGINT(Ctrl+C) 또는 SIGTERM을 받을 때까지 대기합니다.
+async fn shutdown_signal() {
+    let ctrl_c = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            error!("SIGINT 핸들러 등록 실패: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                error!("SIGTERM 핸들러 등록 실패: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = ctrl_c => {}
+        _ = terminate => {}
+    }
+}
+
+/// 프로세스 상태와 계정/리전별 구성 요소 상태를 JSON으로 반환합니다 (항상 200).
+async fn serve_health(
+    (status, ready_max_age): (CollectionStatus, Duration),
+) -> Result<impl warp::Reply, Infallible> {
+    Ok(warp::reply::json(
+        &status.report(chrono::Utc::now(), ready_max_age),
+    ))
+}
+
+/// 첫 수집에 성공하기 전이나 마지막 성공이 오래되었으면 503을 반환합니다.
+async fn serve_ready(
+    (status, ready_max_age): (CollectionStatus, Duration),
+) -> Result<impl warp::Reply, Infallible> {
+    if status.is_ready(chrono::Utc::now(), ready_max_age) {
+        Ok(warp::reply::with_status("OK", StatusCode::OK))
+    } else {
+        Ok(warp::reply::with_status(
+            "NOT READY",
+            StatusCode::SERVICE_UNAVAILABLE,
+        ))
+    }
 }
 
 #[tokio::main]
@@ -51,58 +212,145 @@ async fn main() -> anyhow::Result<()> {
     info!("RDS 메트릭 수집기 시작...");
 
     // 설정 로드
-    let config = Settings::new()?;
+    let config = Arc::new(Settings::new()?);
     info!("설정 로드 완료: {:?}", config);
 
-    // AWS SDK 설정
-    let mut aws_config_builder = aws_config::defaults(BehaviorVersion::latest())
-        .region(aws_config::Region::new(config.aws.region.clone()));
-
-    // AWS 프로필 설정이 있는 경우 적용
-    if let Some(credentials) = &config.aws.credentials {
-        aws_config_builder = aws_config_builder
-            .profile_name(&credentials.profile)
-            .credentials_provider(
-                aws_config::profile::ProfileFileCredentialsProvider::builder()
-                    .profile_name(&credentials.profile)
-                    .build(),
-            );
+    // 설정 다시 읽기: 파일 변경, POST /-/reload, SIGHUP
+    let (reloader, config_updates) = ConfigReloader::new(Arc::clone(&config));
+    let reloader = Arc::new(reloader);
+    let _config_watcher = match reloader.watch_files(CONFIG_DIR) {
+        Ok(watcher) => Some(watcher),
+        Err(e) => {
+            warn!("설정 파일 감시를 시작할 수 없습니다: {}", e);
+            None
+        }
+    };
+    if let Err(e) = reloader.reload_on_sighup() {
+        warn!("SIGHUP 핸들러 등록 실패: {}", e);
     }
 
-    let aws_config = aws_config_builder.load().await;
-
     // CloudWatch 수집기 설정
-    let cw_config = CWConfig {
-        period: config.cloudwatch.period,
-        stat: config.cloudwatch.stat.clone(),
-        retry_attempts: config.cloudwatch.retry_attempts,
-        retry_delay: Duration::seconds(config.cloudwatch.retry_delay as i64),
-    };
+    let cw_config = config.cloudwatch.metric_config();
 
     // RDS 매니저 설정
     let rds_config = RdsConfig {
-        target_tag_key: config.target.tag_key,
-        target_tag_value: config.target.tag_value,
+        target_filter: config.target.tag_filter().map_err(anyhow::Error::msg)?,
+        // 조회 주기마다 새 목록을 불러오도록 캐시 유지 시간을 조회 주기에 맞춥니다
+        cache_ttl: std::time::Duration::from_secs(config.exporter.discovery_interval),
         ..Default::default()
     };
 
-    // AWS 클라이언트 초기화
-    let rds_client = RdsClient::new(&aws_config);
-    let cloudwatch_client = CloudWatchClient::new(&aws_config);
+    // 계정/리전별 AWS 클라이언트 및 컴포넌트 초기화
+    let mut targets = Vec::new();
+    let mut discoveries = Vec::new();
+    if config.aws.accounts.is_empty() {
+        // 모든 리전이 같은 자격 증명을 쓰므로 계정 ID는 한 번만 조회합니다
+        let mut caller_account = None;
+        for region in config.aws.regions() {
+            let aws_config = load_aws_config(&region, config.aws.credentials.as_ref()).await;
+            if caller_account.is_none() {
+                caller_account = Some(caller_account_id(&aws_config).await);
+            }
+            let account_id = caller_account.as_deref().unwrap_or_default();
+            info!("수집 리전 추가: {} (계정: {})", region, account_id);
+            let (discovery, target) =
+                collection_target(&aws_config, account_id, region, &rds_config, &cw_config);
+            discoveries.push(discovery);
+            targets.push(target);
+        }
+    } else {
+        for account in &config.aws.accounts {
+            let account_id = account.account_id().unwrap_or_default();
+            for region in config.aws.account_regions(account) {
+                let base_config = load_aws_config(&region, config.aws.credentials.as_ref()).await;
+                let aws_config = assume_role_config(&base_config, account).await;
+                info!(
+                    "수집 대상 추가: {}/{} ({})",
+                    account_id, region, account.role_arn
+                );
+                let (discovery, target) =
+                    collection_target(&aws_config, account_id, region, &rds_config, &cw_config);
+                discoveries.push(discovery);
+                targets.push(target);
+            }
+        }
+    }
 
-    // 컴포넌트 초기화
-    let rds_manager = RdsInstanceManager::new(rds_client, rds_config);
-    let cloudwatch = CloudWatchCollector::new(cloudwatch_client, cw_config);
-    let prometheus_publisher = PrometheusPublisher::new();
-    let publishers: Vec<Box<dyn MetricPublisher>> = vec![Box::new(prometheus_publisher.clone())];
+    let prometheus_publisher = PrometheusPublisher::new(PrometheusConfig {
+        export_timestamps: config.exporter.export_timestamps,
+        stat_style: config.exporter.stat_style,
+        stale_after_cycles: config.exporter.stale_series_cycles,
+        label_schema: match config.tag_labels.mode {
+            TagLabelMode::Labels => {
+                LabelSchema::default().with_extra_labels(&config.tag_labels.label_names())
+            }
+            TagLabelMode::Info => LabelSchema::default(),
+        },
+    });
+    let mut publishers: Vec<Box<dyn MetricPublisher>> =
+        vec![Box::new(prometheus_publisher.clone())];
+    // 발행자는 목록 순서대로 호출되므로 레지스트리를 푸시하는 Pushgateway 발행자를
+    // Prometheus 발행자 바로 뒤에 둡니다
+    if let Some(pushgateway) = &config.publishers.pushgateway {
+        info!(
+            "Pushgateway 발행자 추가: {} (job: {})",
+            pushgateway.url, pushgateway.job
+        );
+        publishers.push(Box::new(PushgatewayPublisher::new(
+            PushgatewayConfig::from_settings(pushgateway),
+            prometheus_publisher.clone(),
+        )?));
+    }
+    if let Some(remote_write) = &config.publishers.remote_write {
+        info!("remote write 발행자 추가: {}", remote_write.url);
+        publishers.push(Box::new(RemoteWritePublisher::new(
+            RemoteWriteConfig::from_settings(
+                remote_write,
+                config.exporter.stat_style,
+                config.exporter.stale_series_cycles,
+            ),
+        )?));
+    }
+    if let Some(otlp) = &config.publishers.otlp {
+        info!("OTLP 발행자 추가: {} ({:?})", otlp.endpoint, otlp.protocol);
+        publishers.push(Box::new(OtlpPublisher::new(OtlpConfig::from_settings(
+            otlp,
+            config.exporter.stat_style,
+        ))?));
+    }
+    if let Some(influxdb) = &config.publishers.influxdb {
+        info!("InfluxDB 발행자 추가: {}", influxdb.url);
+        publishers.push(Box::new(InfluxDbPublisher::new(
+            InfluxDbConfig::from_settings(influxdb, config.exporter.stat_style),
+        )?));
+    }
+    if let Some(statsd) = &config.publishers.statsd {
+        info!("StatsD 발행자 추가: {}", statsd.address);
+        publishers.push(Box::new(StatsdPublisher::new(
+            StatsdConfig::from_settings(statsd, config.exporter.stat_style),
+        )?));
+    }
 
     // 메트릭 수집기 초기화
+    let status = CollectionStatus::default();
+    let collection_interval = Duration::seconds(config.exporter.collection_interval as i64);
+    // 마지막 성공이 이 시간보다 오래되면 준비되지 않은 상태로 봅니다
+    let ready_max_age = collection_interval * config.exporter.ready_stale_cycles as i32;
+    let mut discovery = DiscoveryLoop::new(
+        discoveries,
+        Duration::seconds(config.exporter.discovery_interval as i64),
+        status.clone(),
+    )
+    .with_config_updates(config_updates.clone());
     let mut collector = RdsMetricCollector::new(
-        cloudwatch,
-        rds_manager,
+        targets,
+        MetricCatalog::new(&config.metrics),
+        config.tag_labels.clone(),
         publishers,
-        Duration::seconds(config.exporter.collection_interval as i64),
-    );
+        collection_interval,
+        status.clone(),
+    )
+    .with_config_updates(config_updates);
 
     // Prometheus 메트릭 엔드포인트 설정
     let prometheus_publisher = warp::any().map(move || prometheus_publisher.clone());
@@ -112,37 +360,92 @@ async fn main() -> anyhow::Result<()> {
         .and(prometheus_publisher.clone())
         .and_then(serve_metrics);
 
-    let health_route = warp::path("health").and(warp::get()).and_then(serve_health);
+    let status = warp::any().map(move || (status.clone(), ready_max_age));
+
+    let health_route = warp::path("health")
+        .and(warp::get())
+        .and(status.clone())
+        .and_then(serve_health);
+
+    let ready_route = warp::path("ready")
+        .and(warp::get())
+        .and(status)
+        .and_then(serve_ready);
+
+    let reload_route = warp::path!("-" / "reload")
+        .and(warp::post())
+        .and(warp::any().map(move || Arc::clone(&reloader)))
+        .and_then(serve_reload);
 
-    let routes = metrics_route.or(health_route);
+    let routes = metrics_route
+        .or(health_route)
+        .or(ready_route)
+        .or(reload_route);
 
     // 서버 주소 설정
     let addr: SocketAddr = format!("{}:{}", config.exporter.host, config.exporter.port)
         .parse()
         .expect("Invalid address");
 
+    // 종료 신호는 watch 채널로 수집기와 HTTP 서버에 전달합니다
+    let (shutdown_tx, shutdown_rx) = watch::channel(false);
+    let mut server_shutdown = shutdown_rx.clone();
+    let (_, server) = warp::serve(routes).bind_with_graceful_shutdown(addr, async move {
+        let _ = server_shutdown.changed().await;
+    });
+
+    // 첫 조회가 오래 걸려도 /health와 /ready가 응답하도록 HTTP 서버를 먼저 시작합니다
+    let mut server_handle = tokio::spawn(server);
+
+    // 첫 수집 주기가 대상을 가지고 시작하도록 한 번 먼저 조회합니다
+    discovery.discover_all().await;
+
     info!(
-        "메트릭 수집 시작 (수집 주기: {}초)",
-        config.exporter.collection_interval
+        "메트릭 수집 시작 (수집 주기: {}초, 대상 조회 주기: {}초)",
+        config.exporter.collection_interval, config.exporter.discovery_interval
     );
 
-    // 수집기와 HTTP 서버 동시 실행
-    let server = warp::serve(routes).run(addr);
-    let collector_handle = tokio::spawn(async move {
-        if let Err(e) = collector.start_collection().await {
+    // 수집기와 대상 조회 동시 실행
+    let discovery_shutdown = shutdown_rx.clone();
+    let mut discovery_handle = tokio::spawn(async move { discovery.run(discovery_shutdown).await });
+    let mut collector_handle = tokio::spawn(async move {
+        if let Err(e) = collector.start_collection(shutdown_rx).await {
             error!("메트릭 수집 중 오류 발생: {}", e);
         }
     });
 
-    // 서버와 수집기 실행
     tokio::select! {
-        _ = server => {
+        _ = shutdown_signal() => {
+            info!("종료 신호 수신, 진행 중인 작업을 마무리합니다");
+        }
+        _ = &mut server_handle => {
             error!("HTTP 서버 종료");
+            return Ok(());
         }
-        _ = collector_handle => {
+        _ = &mut collector_handle => {
             error!("메트릭 수집기 종료");
+            return Ok(());
+        }
+        _ = &mut discovery_handle => {
+            error!("대상 조회 작업 종료");
+            return Ok(());
         }
     }
 
+    // 새 수집 주기를 시작하지 않고, 진행 중인 주기와 HTTP 요청이 끝나기를 기다립니다
+    let _ = shutdown_tx.send(true);
+    let grace_period = std::time::Duration::from_secs(config.exporter.shutdown_grace_period);
+    match tokio::time::timeout(grace_period, async {
+        let _ = tokio::join!(server_handle, collector_handle, discovery_handle);
+    })
+    .await
+    {
+        Ok(_) => info!("정상 종료"),
+        Err(_) => warn!(
+            "유예 시간({}초) 내에 작업이 끝나지 않아 종료합니다",
+            grace_period.as_secs()
+        ),
+    }
+
     Ok(())
 }
@@ -0,0 +1,97 @@
+// src/metrics/catalog.rs
+use crate::config::{MetricSetSettings, MetricSettings, MetricsSettings};
+use crate::glob::glob_match;
+
+/// 엔진 이름에 따라 수집할 메트릭 목록을 결정합니다.
+///
+/// 엔진과 일치하는 모든 세트를 설정 순서대로 합치며, 같은 이름의 메트릭은
+/// 나중에 나온 정의가 앞선 정의를 대체합니다.
+#[derive(Debug, Clone)]
+pub struct MetricCatalog {
+    instance: Vec<MetricSetSettings>,
+    cluster: Vec<MetricSetSettings>,
+}
+
+impl MetricCatalog {
+    pub fn new(settings: &MetricsSettings) -> Self {
+        Self {
+            instance: settings.instance.clone(),
+            cluster: settings.cluster.clone(),
+        }
+    }
+
+    pub fn instance_metrics(&self, engine: &str) -> Vec<MetricSettings> {
+        Self::resolve(&self.instance, engine)
+    }
+
+    pub fn cluster_metrics(&self, engine: &str) -> Vec<MetricSettings> {
+        Self::resolve(&self.cluster, engine)
+    }
+
+    fn resolve(sets: &[MetricSetSettings], engine: &str) -> Vec<MetricSettings> {
+        let mut metrics: Vec<MetricSettings> = Vec::new();
+
+        for set in sets {
+            if !set
+                .engines
+                .iter()
+                .any(|pattern| glob_match(pattern, engine))
+            {
+                continue;
+            }
+            for metric in &set.metrics {
+                match metrics.iter_mut().find(|m| m.name == metric.name) {
+                    Some(existing) => *existing = metric.clone(),
+                    None => metrics.push(metric.clone()),
+                }
+            }
+        }
+
+        metrics
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn metric(name: &str, stat: Option<&str>) -> MetricSettings {
+        MetricSettings {
+            name: name.to_string(),
+            stats: stat.map(String::from).into_iter().collect(),
+            period: None,
+            unit: None,
+        }
+    }
+
+    #[test]
+    fn test_sets_are_merged_in_order() {
+        let catalog = MetricCatalog::new(&MetricsSettings {
+            instance: vec![
+                MetricSetSettings {
+                    engines: vec!["*".to_string()],
+                    metrics: vec![metric("CPUUtilization", None), metric("ReadIOPS", None)],
+                },
+                MetricSetSettings {
+                    engines: vec!["aurora-*".to_string()],
+                    metrics: vec![
+                        metric("CPUUtilization", Some("Maximum")),
+                        metric("AuroraReplicaLag", None),
+                    ],
+                },
+            ],
+            cluster: Vec::new(),
+        });
+
+        assert_eq!(
+            catalog.instance_metrics("aurora-postgresql"),
+            vec![
+                metric("CPUUtilization", Some("Maximum")),
+                metric("ReadIOPS", None),
+                metric("AuroraReplicaLag", None),
+            ]
+        );
+        assert_eq!(catalog.instance_metrics("postgres").len(), 2);
+        assert!(catalog.cluster_metrics("aurora-mysql").is_empty());
+    }
+}
@@ -1,15 +1,105 @@
 // src/metrics/collector.rs
-use crate::aws::cloudwatch::CloudWatchCollector;
-use crate::aws::rds::RdsInstanceManager;
+use crate::aws::cloudwatch::{CloudWatchCollector, MetricQuery};
+use crate::config::{MetricSettings, Settings, TagLabelMode, TagLabelSettings};
+use crate::exporter_metrics::{
+    CLOUDWATCH_INCOMPLETE_RESULTS, COLLECTION_CYCLE_DURATION, DATAPOINTS_PUBLISHED,
+};
+use crate::labels::{CLUSTER_LABELS, INSTANCE_LABELS};
+use crate::metrics::catalog::MetricCatalog;
+use crate::metrics::discovery::{target_name, DiscoveredTargets};
+use crate::metrics::status::{CollectionStatus, Component};
 use async_trait::async_trait;
-use chrono::{Duration, Utc};
+use aws_sdk_rds::types::{DbCluster, DbInstance, Tag};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::join_all;
 use std::collections::HashMap;
+use std::sync::Arc;
+use tokio::sync::watch;
 use tracing::{debug, error, info, warn};
 
+/// 메트릭이 수집된 CloudWatch 차원의 범위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricScope {
+    /// `DBInstanceIdentifier` 차원
+    #[default]
+    Instance,
+    /// `DBClusterIdentifier` 차원
+    Cluster,
+}
+
+impl MetricScope {
+    pub fn dimension_name(&self) -> &'static str {
+        match self {
+            MetricScope::Instance => "DBInstanceIdentifier",
+            MetricScope::Cluster => "DBClusterIdentifier",
+        }
+    }
+}
+
+/// 메트릭 포인트의 종류
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum MetricKind {
+    /// CloudWatch에서 조회한 값
+    #[default]
+    CloudWatch,
+    /// 값이 항상 1이고 레이블로 정보를 전달하는 메트릭. 이름은 `rds_<metric_name>`,
+    /// 레이블은 `additional_tags` 전체이며 `stat`은 붙지 않습니다.
+    Info,
+}
+
+/// 범위별로 고정된 레이블 이름 목록. 발행자는 이 목록으로 메트릭 패밀리를 등록하고,
+/// 대상에 없는 레이블은 빈 문자열로 채웁니다.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LabelSchema {
+    pub instance: Vec<String>,
+    pub cluster: Vec<String>,
+}
+
+impl Default for LabelSchema {
+    fn default() -> Self {
+        Self {
+            instance: INSTANCE_LABELS.iter().map(|s| s.to_string()).collect(),
+            cluster: CLUSTER_LABELS.iter().map(|s| s.to_string()).collect(),
+        }
+    }
+}
+
+impl LabelSchema {
+    /// 인스턴스와 클러스터 메트릭 모두에 레이블을 추가합니다.
+    pub fn with_extra_labels(mut self, labels: &[String]) -> Self {
+        self.instance.extend_from_slice(labels);
+        self.cluster.extend_from_slice(labels);
+        self
+    }
+
+    pub fn labels(&self, scope: MetricScope) -> &[String] {
+        match scope {
+            MetricScope::Instance => &self.instance,
+            MetricScope::Cluster => &self.cluster,
+        }
+    }
+}
+
+/// 계정/리전 하나의 수집 결과
+struct TargetMetrics {
+    metrics: Vec<MetricPoint>,
+    /// CloudWatch 조회에 성공했는지 여부. 실패해도 정보 메트릭은 `metrics`에 담깁니다.
+    cloudwatch_ok: bool,
+}
+
+/// (차원 이름, 차원 값) -> 해당 대상의 범위와 레이블
+type TargetLabels = HashMap<(&'static str, String), (MetricScope, HashMap<String, String>)>;
+
 #[derive(Debug, Clone)]
 pub struct MetricPoint {
     pub value: f64,
+    /// CloudWatch 데이터 포인트 시각
+    pub timestamp: DateTime<Utc>,
     pub metric_name: String,
+    /// CloudWatch 통계 (Average, Maximum, p99 등)
+    pub stat: String,
+    pub scope: MetricScope,
+    pub kind: MetricKind,
     pub additional_tags: HashMap<String, String>,
 }
 
@@ -17,190 +107,503 @@ pub struct MetricPoint {
 pub trait MetricPublisher: Send + Sync {
     async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()>;
     fn gather(&self) -> Vec<prometheus::proto::MetricFamily>;
+
+    /// 종료 전에 버퍼에 남은 데이터를 내보냅니다. 푸시 방식 발행자가 구현합니다.
+    async fn flush(&self) -> anyhow::Result<()> {
+        Ok(())
+    }
 }
 
-pub struct RdsMetricCollector {
+/// 계정과 리전 하나의 CloudWatch 수집 대상
+pub struct CollectionTarget {
+    /// 수집 계정 ID (기본 자격 증명의 계정 ID를 조회하지 못했으면 빈 문자열)
+    account_id: String,
+    region: String,
     cloudwatch: CloudWatchCollector,
-    rds_manager: RdsInstanceManager,
+    /// 백그라운드 대상 조회가 전달하는 최신 인스턴스/클러스터 목록
+    discovered: watch::Receiver<Arc<DiscoveredTargets>>,
+}
+
+impl CollectionTarget {
+    pub fn new(
+        account_id: impl Into<String>,
+        region: impl Into<String>,
+        cloudwatch: CloudWatchCollector,
+        discovered: watch::Receiver<Arc<DiscoveredTargets>>,
+    ) -> Self {
+        Self {
+            account_id: account_id.into(),
+            region: region.into(),
+            cloudwatch,
+            discovered,
+        }
+    }
+
+    fn name(&self) -> String {
+        target_name(&self.account_id, &self.region)
+    }
+}
+
+pub struct RdsMetricCollector {
+    targets: Vec<CollectionTarget>,
+    catalog: MetricCatalog,
+    tag_labels: TagLabelSettings,
     publishers: Vec<Box<dyn MetricPublisher>>,
     collection_interval: Duration,
+    status: CollectionStatus,
+    /// 다시 읽은 설정. 각 수집 주기 시작 전에 적용합니다.
+    config_updates: Option<watch::Receiver<Arc<Settings>>>,
 }
 
 impl RdsMetricCollector {
     pub fn new(
-        cloudwatch: CloudWatchCollector,
-        rds_manager: RdsInstanceManager,
+        targets: Vec<CollectionTarget>,
+        catalog: MetricCatalog,
+        tag_labels: TagLabelSettings,
         publishers: Vec<Box<dyn MetricPublisher>>,
         collection_interval: Duration,
+        status: CollectionStatus,
     ) -> Self {
         Self {
-            cloudwatch,
-            rds_manager,
+            targets,
+            catalog,
+            tag_labels,
             publishers,
             collection_interval,
+            status,
+            config_updates: None,
         }
     }
 
-    pub async fn start_collection(&mut self) -> anyhow::Result<()> {
-        loop {
+    pub fn with_config_updates(mut self, updates: watch::Receiver<Arc<Settings>>) -> Self {
+        self.config_updates = Some(updates);
+        self
+    }
+
+    /// 새 설정이 있으면 메트릭 카탈로그와 CloudWatch 설정을 교체합니다.
+    /// 대상 필터는 [`DiscoveryLoop`](crate::metrics::discovery::DiscoveryLoop)가 적용합니다.
+    /// 주기 사이에서만 호출되므로 한 주기 안에서 설정이 섞이지 않습니다.
+    fn apply_config_updates(&mut self) {
+        let Some(updates) = self.config_updates.as_mut() else {
+            return;
+        };
+        if !updates.has_changed().unwrap_or(false) {
+            return;
+        }
+        let settings = Arc::clone(&updates.borrow_and_update());
+
+        self.catalog = MetricCatalog::new(&settings.metrics);
+        let metric_config = settings.cloudwatch.metric_config();
+        for target in &mut self.targets {
+            target.cloudwatch.set_config(metric_config.clone());
+        }
+        info!("새 설정 적용 완료 (메트릭 카탈로그, CloudWatch 설정)");
+    }
+
+    /// 종료 신호를 받을 때까지 수집 주기를 반복합니다.
+    ///
+    /// 진행 중인 주기는 끝까지 실행하고, 대기 중에 신호를 받으면 바로 멈춥니다.
+    /// 종료 전에 모든 발행자를 flush합니다.
+    pub async fn start_collection(
+        &mut self,
+        mut shutdown: watch::Receiver<bool>,
+    ) -> anyhow::Result<()> {
+        while !*shutdown.borrow() {
+            self.apply_config_updates();
+
+            let timer = COLLECTION_CYCLE_DURATION.start_timer();
             match self.collect_and_publish().await {
-                Ok(_) => info!("메트릭 수집 및 발행 완료"),
+                Ok(_) => {
+                    info!("메트릭 수집 및 발행 완료");
+                    self.status.record_cycle_success(Utc::now());
+                }
                 Err(e) => error!("메트릭 수집 중 오류 발생: {}", e),
             }
-            tokio::time::sleep(std::time::Duration::from_secs(
-                self.collection_interval.num_seconds() as u64,
-            ))
-            .await;
+            timer.observe_duration();
+
+            tokio::select! {
+                _ = tokio::time::sleep(std::time::Duration::from_secs(
+                    self.collection_interval.num_seconds() as u64,
+                )) => {}
+                // 송신 측이 사라진 경우도 종료로 봅니다
+                _ = shutdown.changed() => break,
+            }
         }
+
+        info!("메트릭 수집 중지, 발행자 flush");
+        for publisher in &self.publishers {
+            if let Err(e) = publisher.flush().await {
+                error!("발행자 flush 실패: {}", e);
+            }
+        }
+
+        Ok(())
     }
 
-    async fn collect_and_publish(&mut self) -> anyhow::Result<()> {
-        let instances = self.rds_manager.get_prd_instances().await?;
-        debug!("수집 대상 인스턴스 조회: {} 개", instances.len());
+    async fn collect_and_publish(&self) -> anyhow::Result<()> {
+        // 대상(계정/리전)별 수집은 동시에 실행하고, 발행은 전체 결과로 한 번만 합니다.
+        // 한 대상의 실패(역할 위임 실패 등)는 다른 대상의 수집을 막지 않습니다.
+        let results = join_all(
+            self.targets
+                .iter()
+                .map(|target| self.collect_target(target)),
+        )
+        .await;
 
         let mut all_metrics = Vec::new();
-        let end_time = Utc::now();
-        let start_time = end_time - Duration::minutes(5);
+        let mut failed_targets = 0;
+        let mut cloudwatch_targets = 0;
+        for (target, result) in self.targets.iter().zip(results) {
+            match result {
+                Ok(TargetMetrics {
+                    metrics,
+                    cloudwatch_ok,
+                }) => {
+                    debug!("{} 수집 완료: {} 개", target.name(), metrics.len());
+                    if cloudwatch_ok {
+                        cloudwatch_targets += 1;
+                    }
+                    all_metrics.extend(metrics.into_iter().map(|mut metric| {
+                        metric
+                            .additional_tags
+                            .insert("account_id".to_string(), target.account_id.clone());
+                        metric
+                            .additional_tags
+                            .insert("region".to_string(), target.region.clone());
+                        metric
+                    }));
+                }
+                Err(e) => {
+                    error!("{} 메트릭 수집 실패: {}", target.name(), e);
+                    failed_targets += 1;
+                }
+            }
+        }
+
+        if failed_targets == self.targets.len() {
+            return Err(anyhow::anyhow!("모든 대상에서 수집에 실패했습니다"));
+        }
+
+        debug!("전체 수집된 메트릭 수: {}", all_metrics.len());
+        DATAPOINTS_PUBLISHED.inc_by(all_metrics.len() as u64);
 
-        for instance in instances {
+        for publisher in &self.publishers {
+            if let Err(e) = publisher.publish(all_metrics.clone()).await {
+                error!("메트릭 발행 실패: {}", e);
+            }
+        }
+
+        // 정보 메트릭만 발행된 주기는 성공으로 보지 않습니다
+        if cloudwatch_targets == 0 {
+            return Err(anyhow::anyhow!(
+                "모든 대상에서 CloudWatch 수집에 실패했습니다"
+            ));
+        }
+
+        Ok(())
+    }
+
+    /// 계정/리전 하나에서 마지막으로 조회된 대상의 CloudWatch 메트릭을 수집합니다.
+    async fn collect_target(&self, target: &CollectionTarget) -> anyhow::Result<TargetMetrics> {
+        let discovered = Arc::clone(&target.discovered.borrow());
+        if discovered.discovered_at.is_none() {
+            return Err(anyhow::anyhow!("대상 조회에 아직 성공하지 못했습니다"));
+        }
+        debug!(
+            "수집 대상 ({}): 인스턴스 {} 개, 클러스터 {} 개",
+            target.name(),
+            discovered.instances.len(),
+            discovered.clusters.len()
+        );
+
+        // 모든 대상의 쿼리를 모아 한 번에 배치 조회하고, 결과는 차원으로 대상에 되돌립니다
+        let mut queries = Vec::new();
+        let mut targets: TargetLabels = HashMap::new();
+        let mut all_metrics = Vec::new();
+
+        for instance in &discovered.instances {
             let instance_id = instance.db_instance_identifier().unwrap_or_default();
             let engine = instance.engine().unwrap_or_default();
-            let tags = self.get_instance_tags(&instance);
+            let tags = self.get_instance_tags(instance);
+            all_metrics.push(Self::instance_info_metric(instance));
+            if let Some(info) = self.tag_info_metric(
+                "instance_tags",
+                "db_instance_identifier",
+                instance_id,
+                instance.tag_list(),
+            ) {
+                all_metrics.push(info);
+            }
 
             debug!(
                 "인스턴스 처리 시작 - ID: {}, 엔진: {}, 태그: {:?}",
                 instance_id, engine, tags
             );
 
-            let metrics_to_collect = match &engine[..] {
-                "aurora-mysql" | "mysql" => self.get_mysql_metrics(),
-                "aurora-postgresql" | "postgres" => self.get_postgresql_metrics(),
-                _ => self.get_common_metrics(),
-            };
+            let metrics_to_collect = self.catalog.instance_metrics(engine);
 
             debug!(
                 "수집할 메트릭 목록 ({:?}): {:?}",
-                engine, metrics_to_collect
+                engine,
+                metrics_to_collect
+                    .iter()
+                    .map(|metric| metric.name.as_str())
+                    .collect::<Vec<_>>()
             );
 
-            let metric_tuples: Vec<(&str, &str, &str, &str)> = metrics_to_collect
-                .iter()
-                .map(|metric_name| {
-                    (
-                        "AWS/RDS",
-                        metric_name.as_str(),
-                        "DBInstanceIdentifier",
-                        instance_id,
-                    )
-                })
-                .collect();
-
-            match self
-                .cloudwatch
-                .collect_all_metrics(metric_tuples, start_time, end_time)
-                .await
-            {
-                Ok(response) => {
-                    for (idx, data) in response.metric_data_results().iter().enumerate() {
-                        let metric_name = &metrics_to_collect[idx];
-
-                        debug!(
-                            "메트릭 데이터 수신 - 인스턴스: {}, 메트릭: {}, 데이터 포인트 수: {}",
-                            instance_id,
-                            metric_name,
-                            data.values().len()
-                        );
+            Self::add_target_queries(
+                MetricScope::Instance,
+                instance_id,
+                &metrics_to_collect,
+                tags,
+                &mut queries,
+                &mut targets,
+            );
+        }
 
-                        for value in data.values() {
-                            let metric = MetricPoint {
-                                value: *value,
-                                metric_name: metric_name.clone(),
-                                additional_tags: tags.clone(),
-                            };
-                            debug!(
-                                "메트릭 포인트 생성 - 이름: {}, 값: {}, 태그: {:?}",
-                                metric.metric_name, metric.value, metric.additional_tags
-                            );
-                            all_metrics.push(metric);
-                        }
-                    }
-                }
-                Err(e) => {
-                    warn!("메트릭 수집 실패 (인스턴스: {}): {}", instance_id, e);
-                    continue;
-                }
+        for cluster in &discovered.clusters {
+            let cluster_id = cluster.db_cluster_identifier().unwrap_or_default();
+            let engine = cluster.engine().unwrap_or_default();
+
+            // 카탈로그에 해당 엔진의 클러스터 메트릭이 없으면 건너뜁니다 (예: Multi-AZ DB 클러스터)
+            let metrics_to_collect = self.catalog.cluster_metrics(engine);
+            if metrics_to_collect.is_empty() {
+                debug!("클러스터 메트릭 없음, 건너뜀: {} ({})", cluster_id, engine);
+                continue;
+            }
+
+            let tags = self.get_cluster_tags(cluster);
+            if let Some(info) = self.tag_info_metric(
+                "cluster_tags",
+                "db_cluster_identifier",
+                cluster_id,
+                cluster.tag_list(),
+            ) {
+                all_metrics.push(info);
             }
+
+            Self::add_target_queries(
+                MetricScope::Cluster,
+                cluster_id,
+                &metrics_to_collect,
+                tags,
+                &mut queries,
+                &mut targets,
+            );
         }
 
-        debug!("전체 수집된 메트릭 수: {}", all_metrics.len());
+        debug!("배치 조회할 쿼리 수: {}", queries.len());
 
-        for publisher in &self.publishers {
-            if let Err(e) = publisher.publish(all_metrics.clone()).await {
-                error!("메트릭 발행 실패: {}", e);
+        let end_time = Utc::now();
+        let start_time = end_time - target.cloudwatch.lookback(&queries);
+
+        let cloudwatch_ok = match target
+            .cloudwatch
+            .collect_batched(queries, start_time, end_time)
+            .await
+        {
+            Ok(results) => {
+                self.status.record_success(
+                    &target.account_id,
+                    &target.region,
+                    Component::CloudWatch,
+                );
+
+                for result in results.iter() {
+                    if !result.is_complete() {
+                        CLOUDWATCH_INCOMPLETE_RESULTS
+                            .with_label_values(&[result.status_label()])
+                            .inc();
+                    }
+
+                    let query = &result.query;
+                    let Some((scope, tags)) = targets
+                        .get(&(query.dimension_name.as_str(), query.dimension_value.clone()))
+                    else {
+                        warn!(
+                            "대상을 찾을 수 없는 결과 무시: {}={}",
+                            query.dimension_name, query.dimension_value
+                        );
+                        continue;
+                    };
+
+                    debug!(
+                        "메트릭 데이터 수신 - 대상: {}, 메트릭: {} ({}), 데이터 포인트 수: {}",
+                        query.dimension_value,
+                        query.metric_name,
+                        result.stat,
+                        result.values.len()
+                    );
+
+                    // 같은 시계열에는 가장 최근 데이터 포인트 하나만 발행합니다
+                    let Some((timestamp, value)) = result.latest() else {
+                        continue;
+                    };
+
+                    let metric = MetricPoint {
+                        value,
+                        timestamp,
+                        metric_name: query.metric_name.clone(),
+                        stat: result.stat.clone(),
+                        scope: *scope,
+                        kind: MetricKind::CloudWatch,
+                        additional_tags: tags.clone(),
+                    };
+                    debug!(
+                        "메트릭 포인트 생성 - 이름: {}, 값: {}, 시각: {}, 태그: {:?}",
+                        metric.metric_name, metric.value, metric.timestamp, metric.additional_tags
+                    );
+                    all_metrics.push(metric);
+                }
+                true
             }
-        }
+            Err(e) => {
+                warn!("메트릭 수집 실패 ({}): {}", target.name(), e);
+                self.status.record_failure(
+                    &target.account_id,
+                    &target.region,
+                    Component::CloudWatch,
+                    &e,
+                );
+                false
+            }
+        };
 
-        Ok(())
+        Ok(TargetMetrics {
+            metrics: all_metrics,
+            cloudwatch_ok,
+        })
     }
 
-    fn get_common_metrics(&self) -> Vec<String> {
-        vec![
-            "CPUUtilization".to_string(),
-            "FreeableMemory".to_string(),
-            "FreeStorageSpace".to_string(),
-            "DatabaseConnections".to_string(),
-            "ReadIOPS".to_string(),
-            "WriteIOPS".to_string(),
-            "ReadLatency".to_string(),
-            "WriteLatency".to_string(),
-            "DiskQueueDepth".to_string(), // 스토리지 I/O 요청 대기열 길이
-            "ReadThroughput".to_string(), // 읽기 전송량 (MB/s)
-            "WriteThroughput".to_string(), // 쓰기 전송량 (MB/s)
-            "NetworkReceiveThroughput".to_string(), // 수신 네트워크 트래픽
-            "NetworkTransmitThroughput".to_string(), // 송신 네트워크 트래픽
-            "LockWaitTime".to_string(),   // 락 대기 시간
-            "LockContention".to_string(), // 락 충돌 횟수
-            "QueryExecutionTime".to_string(), // 평균 쿼리 실행 시간
-            "QueryCount".to_string(),     // 총 쿼리 실행 횟수
-            "SlowQueries".to_string(),    // 임계치를 초과한 느린 쿼리 수
-            "BackupStatus".to_string(),   // 백업 상태
-            "SnapshotAge".to_string(),    // 최신 스냅샷 생성 시각
-        ]
-    }
-
-    fn get_mysql_metrics(&self) -> Vec<String> {
-        let mut metrics = self.get_common_metrics();
-        metrics.extend(vec![
-            "Queries".to_string(),
-            "ThreadsRunning".to_string(),
-            "InnodbBufferPoolHits".to_string(),
-            "InnodbBufferPoolReadRequests".to_string(), // 버퍼 풀 읽기 요청 수
-            "InnodbBufferPoolReads".to_string(),        // 실제 읽기 수
-            "DeadlocksCount".to_string(),
-        ]);
-        metrics
-    }
-
-    fn get_postgresql_metrics(&self) -> Vec<String> {
-        let mut metrics = self.get_common_metrics();
-        metrics.extend(vec![
-            "ActiveTransactions".to_string(),
-            "BufferCacheHitRatio".to_string(),
-            "IndexHitRatio".to_string(),
-            "Deadlocks".to_string(),
-            "TemporaryTables".to_string(),    // 임시 테이블 사용률
-            "ReplicationLag".to_string(),     // 복제 지연 시간
-            "CheckpointDuration".to_string(), // 체크포인트 소요 시간
-            "WALWriteLatency".to_string(),    // WAL 쓰기 지연 시간
-        ]);
-        metrics
+    /// 인스턴스 또는 클러스터 하나의 쿼리를 배치에 추가하고 레이블을 등록합니다.
+    fn add_target_queries(
+        scope: MetricScope,
+        target_id: &str,
+        metrics_to_collect: &[MetricSettings],
+        tags: HashMap<String, String>,
+        queries: &mut Vec<MetricQuery>,
+        targets: &mut TargetLabels,
+    ) {
+        let dimension_name = scope.dimension_name();
+        queries.extend(metrics_to_collect.iter().map(|metric| MetricQuery {
+            stats: metric.stats.clone(),
+            period: metric.period,
+            unit: metric.unit.clone(),
+            ..MetricQuery::new("AWS/RDS", &metric.name, dimension_name, target_id)
+        }));
+        targets.insert((dimension_name, target_id.to_string()), (scope, tags));
     }
 
-    fn get_instance_tags(
+    /// 설정된 AWS 태그를 레이블 이름으로 변환합니다. 대상에 없는 태그는 빈 문자열입니다.
+    fn aws_tag_labels(&self, aws_tags: &[Tag]) -> HashMap<String, String> {
+        self.tag_labels
+            .keys
+            .iter()
+            .map(|key| {
+                let value = aws_tags
+                    .iter()
+                    .find(|tag| tag.key() == Some(key.key.as_str()))
+                    .and_then(|tag| tag.value())
+                    .unwrap_or_default();
+                (key.label_name(), value.to_string())
+            })
+            .collect()
+    }
+
+    /// `info` 방식일 때 대상의 태그를 담은 정보 메트릭을 만듭니다.
+    fn tag_info_metric(
         &self,
-        instance: &aws_sdk_rds::types::DbInstance,
-    ) -> HashMap<String, String> {
+        metric_name: &str,
+        id_label: &str,
+        target_id: &str,
+        aws_tags: &[Tag],
+    ) -> Option<MetricPoint> {
+        if self.tag_labels.mode != TagLabelMode::Info || self.tag_labels.keys.is_empty() {
+            return None;
+        }
+
+        let mut tags = self.aws_tag_labels(aws_tags);
+        tags.insert(id_label.to_string(), target_id.to_string());
+
+        Some(MetricPoint {
+            value: 1.0,
+            timestamp: Utc::now(),
+            metric_name: metric_name.to_string(),
+            stat: String::new(),
+            scope: MetricScope::default(),
+            kind: MetricKind::Info,
+            additional_tags: tags,
+        })
+    }
+
+    /// DescribeDBInstances 메타데이터를 담은 `rds_instance_info` 정보 메트릭
+    fn instance_info_metric(instance: &DbInstance) -> MetricPoint {
+        let flag = |value: Option<bool>| value.map(|v| v.to_string()).unwrap_or_default();
+        let number = |value: Option<i32>| value.map(|v| v.to_string()).unwrap_or_default();
+        let parameter_groups = instance
+            .db_parameter_groups()
+            .iter()
+            .filter_map(|group| group.db_parameter_group_name())
+            .collect::<Vec<_>>()
+            .join(",");
+
+        let labels = [
+            (
+                "db_instance_identifier",
+                instance
+                    .db_instance_identifier()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            (
+                "status",
+                instance
+                    .db_instance_status()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            ("multi_az", flag(instance.multi_az())),
+            (
+                "storage_type",
+                instance.storage_type().unwrap_or_default().to_string(),
+            ),
+            ("allocated_storage", number(instance.allocated_storage())),
+            ("iops", number(instance.iops())),
+            ("parameter_group", parameter_groups),
+            (
+                "ca_certificate_identifier",
+                instance
+                    .ca_certificate_identifier()
+                    .unwrap_or_default()
+                    .to_string(),
+            ),
+            (
+                "performance_insights_enabled",
+                flag(instance.performance_insights_enabled()),
+            ),
+            ("publicly_accessible", flag(instance.publicly_accessible())),
+            ("deletion_protection", flag(instance.deletion_protection())),
+        ];
+
+        MetricPoint {
+            value: 1.0,
+            timestamp: Utc::now(),
+            metric_name: "instance_info".to_string(),
+            stat: String::new(),
+            scope: MetricScope::Instance,
+            kind: MetricKind::Info,
+            additional_tags: labels
+                .into_iter()
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+
+    fn get_instance_tags(&self, instance: &DbInstance) -> HashMap<String, String> {
         let mut tags = HashMap::new();
+        if self.tag_labels.mode == TagLabelMode::Labels {
+            tags.extend(self.aws_tag_labels(instance.tag_list()));
+        }
 
         if let Some(id) = instance.db_instance_identifier() {
             tags.insert("db_instance_identifier".to_string(), id.to_string());
@@ -220,4 +623,359 @@ impl RdsMetricCollector {
 
         tags
     }
+
+    /// 클러스터 레이블. 쓰기/읽기 멤버는 인스턴스 ID를 정렬하여 쉼표로 연결합니다.
+    fn get_cluster_tags(&self, cluster: &DbCluster) -> HashMap<String, String> {
+        let mut writers = Vec::new();
+        let mut readers = Vec::new();
+        for member in cluster.db_cluster_members() {
+            if let Some(id) = member.db_instance_identifier() {
+                if member.is_cluster_writer().unwrap_or(false) {
+                    writers.push(id);
+                } else {
+                    readers.push(id);
+                }
+            }
+        }
+        writers.sort_unstable();
+        readers.sort_unstable();
+
+        let mut tags = HashMap::new();
+        if self.tag_labels.mode == TagLabelMode::Labels {
+            tags.extend(self.aws_tag_labels(cluster.tag_list()));
+        }
+        tags.insert(
+            "db_cluster_identifier".to_string(),
+            cluster
+                .db_cluster_identifier()
+                .unwrap_or_default()
+                .to_string(),
+        );
+        tags.insert(
+            "engine".to_string(),
+            cluster.engine().unwrap_or_default().to_string(),
+        );
+        tags.insert(
+            "engine_version".to_string(),
+            cluster.engine_version().unwrap_or_default().to_string(),
+        );
+        tags.insert("writer".to_string(), writers.join(","));
+        tags.insert("readers".to_string(), readers.join(","));
+
+        tags
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::cloudwatch::MetricConfig;
+    use crate::aws::test_util::ReplayConnector;
+    use crate::config::{MetricSetSettings, MetricsSettings};
+    use crate::metrics::discovery::DiscoveredTargets;
+    use aws_credential_types::provider::error::CredentialsError;
+    use aws_credential_types::provider::{future, ProvideCredentials};
+    use aws_sdk_cloudwatch::config::retry::RetryConfig;
+    use aws_sdk_cloudwatch::config::{Credentials, Region};
+    use aws_sdk_rds::types::DbParameterGroupStatus;
+    use parking_lot::Mutex;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[derive(Default)]
+    struct CountingPublisher {
+        published: Mutex<Vec<MetricPoint>>,
+        flushed: AtomicUsize,
+    }
+
+    #[async_trait]
+    impl MetricPublisher for Arc<CountingPublisher> {
+        async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+            self.published.lock().extend(metrics);
+            Ok(())
+        }
+
+        fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+            Vec::new()
+        }
+
+        async fn flush(&self) -> anyhow::Result<()> {
+            self.flushed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    const EMPTY_METRIC_DATA: &str = r#"<GetMetricDataResponse xmlns="http://monitoring.amazonaws.com/doc/2010-08-01/">
+  <GetMetricDataResult><MetricDataResults></MetricDataResults></GetMetricDataResult>
+</GetMetricDataResponse>"#;
+
+    /// 역할 위임이 거부된 계정의 자격 증명
+    #[derive(Debug)]
+    struct DeniedAssumeRole;
+
+    impl ProvideCredentials for DeniedAssumeRole {
+        fn provide_credentials<'a>(&'a self) -> future::ProvideCredentials<'a>
+        where
+            Self: 'a,
+        {
+            future::ProvideCredentials::ready(Err(CredentialsError::provider_error(
+                "AccessDenied: sts:AssumeRole",
+            )))
+        }
+    }
+
+    /// `db-1`(mysql) 인스턴스 하나가 조회된 대상. CloudWatch 응답은 `connector`가 돌려줍니다.
+    fn collection_target(account_id: &str, connector: &ReplayConnector) -> CollectionTarget {
+        collection_target_with_credentials(
+            account_id,
+            connector,
+            Credentials::new("test", "test", None, None, "test"),
+        )
+    }
+
+    fn collection_target_with_credentials(
+        account_id: &str,
+        connector: &ReplayConnector,
+        credentials: impl ProvideCredentials + 'static,
+    ) -> CollectionTarget {
+        let conf = aws_sdk_cloudwatch::Config::builder()
+            .behavior_version_latest()
+            .region(Region::new("ap-northeast-2"))
+            .credentials_provider(credentials)
+            .retry_config(RetryConfig::disabled())
+            .http_client(connector.http_client())
+            .build();
+        let (_, discovered) = watch::channel(Arc::new(DiscoveredTargets {
+            instances: vec![DbInstance::builder()
+                .db_instance_identifier("db-1")
+                .engine("mysql")
+                .build()],
+            clusters: Vec::new(),
+            discovered_at: Some(Utc::now()),
+        }));
+
+        CollectionTarget::new(
+            account_id,
+            "ap-northeast-2",
+            CloudWatchCollector::new(
+                aws_sdk_cloudwatch::Client::from_conf(conf),
+                MetricConfig {
+                    retry_attempts: 1,
+                    retry_delay: Duration::zero(),
+                    ..Default::default()
+                },
+            ),
+            discovered,
+        )
+    }
+
+    fn cpu_catalog() -> MetricCatalog {
+        MetricCatalog::new(&MetricsSettings {
+            instance: vec![MetricSetSettings {
+                engines: vec!["*".to_string()],
+                metrics: vec![MetricSettings {
+                    name: "CPUUtilization".to_string(),
+                    stats: Vec::new(),
+                    period: None,
+                    unit: None,
+                }],
+            }],
+            cluster: Vec::new(),
+        })
+    }
+
+    /// 수집 주기 하나를 실행하고 종료한 뒤 준비 상태를 반환합니다.
+    async fn ready_after_one_cycle(targets: Vec<CollectionTarget>) -> bool {
+        let publisher = Arc::new(CountingPublisher::default());
+        let status = CollectionStatus::default();
+        let mut collector = RdsMetricCollector::new(
+            targets,
+            cpu_catalog(),
+            TagLabelSettings::default(),
+            vec![Box::new(publisher.clone())],
+            Duration::hours(1),
+            status.clone(),
+        );
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+        let handle = tokio::spawn(async move { collector.start_collection(shutdown_rx).await });
+
+        // 정보 메트릭은 CloudWatch 실패와 관계없이 발행됩니다
+        tokio::time::timeout(std::time::Duration::from_secs(5), async {
+            while publisher.published.lock().is_empty() {
+                tokio::time::sleep(std::time::Duration::from_millis(10)).await;
+            }
+        })
+        .await
+        .expect("수집 주기가 발행하지 않았습니다");
+        shutdown_tx.send(true).unwrap();
+        handle.await.unwrap().unwrap();
+
+        status.is_ready(Utc::now(), Duration::minutes(5))
+    }
+
+    #[tokio::test]
+    async fn test_cloudwatch_failure_keeps_exporter_not_ready() {
+        // 준비된 응답이 없으면 모든 GetMetricData 호출이 연결 오류로 실패합니다
+        assert!(
+            !ready_after_one_cycle(vec![collection_target("", &ReplayConnector::default())]).await
+        );
+
+        let connector = ReplayConnector::new([EMPTY_METRIC_DATA]);
+        assert!(ready_after_one_cycle(vec![collection_target("", &connector)]).await);
+    }
+
+    #[tokio::test]
+    async fn test_failing_assume_role_target_does_not_stop_others() {
+        let connector = ReplayConnector::new([
+            r#"<GetMetricDataResponse xmlns="http://monitoring.amazonaws.com/doc/2010-08-01/">
+  <GetMetricDataResult><MetricDataResults><member><Id>q0</Id>
+    <Timestamps><member>2025-01-01T00:00:00Z</member></Timestamps><Values><member>12.5</member></Values>
+    <StatusCode>Complete</StatusCode></member></MetricDataResults></GetMetricDataResult>
+</GetMetricDataResponse>"#,
+        ]);
+        let publisher = Arc::new(CountingPublisher::default());
+        let status = CollectionStatus::default();
+        let collector = RdsMetricCollector::new(
+            vec![
+                collection_target_with_credentials(
+                    "111111111111",
+                    &ReplayConnector::default(),
+                    DeniedAssumeRole,
+                ),
+                collection_target("222222222222", &connector),
+            ],
+            cpu_catalog(),
+            TagLabelSettings::default(),
+            vec![Box::new(publisher.clone())],
+            Duration::minutes(1),
+            status.clone(),
+        );
+
+        collector.collect_and_publish().await.unwrap();
+
+        let published = publisher.published.lock();
+        let cloudwatch: Vec<_> = published
+            .iter()
+            .filter(|metric| metric.kind == MetricKind::CloudWatch)
+            .collect();
+        assert_eq!(cloudwatch.len(), 1);
+        assert_eq!(cloudwatch[0].value, 12.5);
+        assert_eq!(cloudwatch[0].additional_tags["account_id"], "222222222222");
+
+        let report = status.report(Utc::now(), Duration::minutes(5));
+        let healthy: Vec<_> = report
+            .targets
+            .iter()
+            .map(|target| {
+                (
+                    target.account_id.as_str(),
+                    target.components["cloudwatch"].healthy,
+                )
+            })
+            .collect();
+        assert_eq!(
+            healthy,
+            vec![("111111111111", false), ("222222222222", true)]
+        );
+    }
+
+    #[test]
+    fn test_config_updates_replace_catalog_between_cycles() {
+        let mut collector = RdsMetricCollector::new(
+            Vec::new(),
+            MetricCatalog::new(&MetricsSettings {
+                instance: Vec::new(),
+                cluster: Vec::new(),
+            }),
+            TagLabelSettings::default(),
+            Vec::new(),
+            Duration::minutes(1),
+            CollectionStatus::default(),
+        );
+        let (tx, rx) = watch::channel(Arc::new(Settings::default()));
+        collector = collector.with_config_updates(rx);
+
+        // 초기 설정은 다시 적용하지 않습니다
+        collector.apply_config_updates();
+        assert!(collector.catalog.instance_metrics("mysql").is_empty());
+
+        let mut settings = Settings::default();
+        settings.metrics.instance = vec![MetricSetSettings {
+            engines: vec!["*".to_string()],
+            metrics: vec![MetricSettings {
+                name: "CPUUtilization".to_string(),
+                stats: Vec::new(),
+                period: None,
+                unit: None,
+            }],
+        }];
+        settings.metrics.cluster.clear();
+        tx.send_replace(Arc::new(settings));
+
+        collector.apply_config_updates();
+        assert_eq!(collector.catalog.instance_metrics("mysql").len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_stops_collection_and_flushes_publishers() {
+        let publisher = Arc::new(CountingPublisher::default());
+        let mut collector = RdsMetricCollector::new(
+            Vec::new(),
+            MetricCatalog::new(&MetricsSettings {
+                instance: Vec::new(),
+                cluster: Vec::new(),
+            }),
+            TagLabelSettings::default(),
+            vec![Box::new(publisher.clone())],
+            Duration::hours(1),
+            CollectionStatus::default(),
+        );
+        let (shutdown_tx, shutdown_rx) = watch::channel(false);
+
+        let handle = tokio::spawn(async move { collector.start_collection(shutdown_rx).await });
+        // 한 시간 대기 중에도 종료 신호를 받으면 바로 멈춰야 합니다
+        tokio::time::sleep(std::time::Duration::from_millis(50)).await;
+        shutdown_tx.send(true).unwrap();
+
+        tokio::time::timeout(std::time::Duration::from_secs(5), handle)
+            .await
+            .expect("종료 신호 후 수집이 멈추지 않았습니다")
+            .unwrap()
+            .unwrap();
+        assert_eq!(publisher.flushed.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn test_instance_info_labels() {
+        let instance = DbInstance::builder()
+            .db_instance_identifier("db-1")
+            .db_instance_status("available")
+            .multi_az(true)
+            .storage_type("gp3")
+            .allocated_storage(100)
+            .db_parameter_groups(
+                DbParameterGroupStatus::builder()
+                    .db_parameter_group_name("default.mysql8.0")
+                    .build(),
+            )
+            .ca_certificate_identifier("rds-ca-rsa2048-g1")
+            .publicly_accessible(false)
+            .build();
+
+        let info = RdsMetricCollector::instance_info_metric(&instance);
+        let label = |name: &str| info.additional_tags.get(name).map(String::as_str);
+
+        assert_eq!(info.kind, MetricKind::Info);
+        assert_eq!(info.value, 1.0);
+        assert_eq!(label("db_instance_identifier"), Some("db-1"));
+        assert_eq!(label("status"), Some("available"));
+        assert_eq!(label("multi_az"), Some("true"));
+        assert_eq!(label("allocated_storage"), Some("100"));
+        assert_eq!(label("parameter_group"), Some("default.mysql8.0"));
+        assert_eq!(label("publicly_accessible"), Some("false"));
+        // 응답에 없는 값도 레이블 집합이 바뀌지 않도록 빈 문자열로 채웁니다
+        assert_eq!(label("iops"), Some(""));
+        assert_eq!(label("deletion_protection"), Some(""));
+        assert_eq!(info.additional_tags.len(), 11);
+    }
 }
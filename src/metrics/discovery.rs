@@ -0,0 +1,341 @@
+// src/metrics/discovery.rs
+use crate::aws::rds::RdsInstanceManager;
+use crate::config::Settings;
+use crate::exporter_metrics::{DISCOVERED_TARGETS, TARGETS_CHANGED};
+use crate::metrics::status::{CollectionStatus, Component};
+use aws_sdk_rds::types::{DbCluster, DbInstance};
+use chrono::{DateTime, Duration, Utc};
+use futures_util::future::join_all;
+use std::collections::BTreeSet;
+use std::sync::Arc;
+use tokio::sync::watch;
+use tracing::{debug, error, info, warn};
+
+/// 대상(계정/리전) 하나에서 마지막으로 찾은 인스턴스와 클러스터
+#[derive(Debug, Clone, Default)]
+pub struct DiscoveredTargets {
+    pub instances: Vec<DbInstance>,
+    pub clusters: Vec<DbCluster>,
+    /// 인스턴스 조회에 처음 성공하기 전에는 `None`
+    pub discovered_at: Option<DateTime<Utc>>,
+}
+
+/// 로그에 표시할 대상 이름 (`계정/리전` 또는 `리전`)
+pub(crate) fn target_name(account_id: &str, region: &str) -> String {
+    if account_id.is_empty() {
+        region.to_string()
+    } else {
+        format!("{}/{}", account_id, region)
+    }
+}
+
+/// 계정/리전 하나의 RDS 조회. 결과는 watch 채널로 수집기에 전달합니다.
+pub struct TargetDiscovery {
+    account_id: String,
+    region: String,
+    rds_manager: RdsInstanceManager,
+    sender: watch::Sender<Arc<DiscoveredTargets>>,
+}
+
+impl TargetDiscovery {
+    pub fn new(
+        account_id: impl Into<String>,
+        region: impl Into<String>,
+        rds_manager: RdsInstanceManager,
+    ) -> (Self, watch::Receiver<Arc<DiscoveredTargets>>) {
+        let (sender, receiver) = watch::channel(Arc::new(DiscoveredTargets::default()));
+        (
+            Self {
+                account_id: account_id.into(),
+                region: region.into(),
+                rds_manager,
+                sender,
+            },
+            receiver,
+        )
+    }
+
+    fn name(&self) -> String {
+        target_name(&self.account_id, &self.region)
+    }
+
+    /// 인스턴스와 클러스터를 한 번 조회하여 변경 사항을 기록하고 수집기에 전달합니다.
+    /// 조회에 실패하면 이전 결과를 유지합니다.
+    pub async fn discover(&mut self, status: &CollectionStatus) {
+        let previous = Arc::clone(&self.sender.borrow());
+
+        let instances = match self.rds_manager.get_prd_instances().await {
+            Ok(instances) => instances,
+            Err(e) => {
+                error!(
+                    "인스턴스 조회 실패 ({}), 이전 대상을 유지합니다: {}",
+                    self.name(),
+                    e
+                );
+                status.record_failure(&self.account_id, &self.region, Component::Rds, &e);
+                return;
+            }
+        };
+
+        // 클러스터 조회 실패는 인스턴스 대상 갱신을 막지 않습니다
+        let clusters = match self.rds_manager.get_prd_clusters().await {
+            Ok(clusters) => {
+                status.record_success(&self.account_id, &self.region, Component::Rds);
+                clusters
+            }
+            Err(e) => {
+                warn!(
+                    "클러스터 조회 실패 ({}), 이전 클러스터를 유지합니다: {}",
+                    self.name(),
+                    e
+                );
+                status.record_failure(&self.account_id, &self.region, Component::Rds, &e);
+                previous.clusters.clone()
+            }
+        };
+
+        // 첫 조회 결과는 변경으로 보지 않습니다
+        if previous.discovered_at.is_some() {
+            self.record_changes(
+                "instance",
+                identifiers(&previous.instances, DbInstance::db_instance_identifier),
+                identifiers(&instances, DbInstance::db_instance_identifier),
+            );
+            self.record_changes(
+                "cluster",
+                identifiers(&previous.clusters, DbCluster::db_cluster_identifier),
+                identifiers(&clusters, DbCluster::db_cluster_identifier),
+            );
+        }
+
+        debug!(
+            "대상 조회 완료 ({}): 인스턴스 {} 개, 클러스터 {} 개",
+            self.name(),
+            instances.len(),
+            clusters.len()
+        );
+        DISCOVERED_TARGETS
+            .with_label_values(&[&self.account_id, &self.region, "instance"])
+            .set(instances.len() as i64);
+        DISCOVERED_TARGETS
+            .with_label_values(&[&self.account_id, &self.region, "cluster"])
+            .set(clusters.len() as i64);
+
+        self.sender.send_replace(Arc::new(DiscoveredTargets {
+            instances,
+            clusters,
+            discovered_at: Some(Utc::now()),
+        }));
+    }
+
+    fn record_changes(&self, scope: &str, previous: BTreeSet<&str>, current: BTreeSet<&str>) {
+        for (change, ids) in [
+            (
+                "added",
+                current.difference(&previous).copied().collect::<Vec<_>>(),
+            ),
+            ("removed", previous.difference(&current).copied().collect()),
+        ] {
+            if ids.is_empty() {
+                continue;
+            }
+            info!(
+                "대상 {} ({}, {}): {}",
+                if change == "added" {
+                    "추가"
+                } else {
+                    "제거"
+                },
+                scope,
+                self.name(),
+                ids.join(", ")
+            );
+            TARGETS_CHANGED
+                .with_label_values(&[&self.account_id, &self.region, scope, change])
+                .inc_by(ids.len() as u64);
+        }
+    }
+}
+
+fn identifiers<T>(items: &[T], id: fn(&T) -> Option<&str>) -> BTreeSet<&str> {
+    items.iter().filter_map(id).collect()
+}
+
+/// 수집 주기와 별개로 대상 조회를 반복하는 백그라운드 작업
+pub struct DiscoveryLoop {
+    targets: Vec<TargetDiscovery>,
+    interval: Duration,
+    status: CollectionStatus,
+    /// 다시 읽은 설정. 대상 필터가 바뀌면 바로 다시 조회합니다.
+    config_updates: Option<watch::Receiver<Arc<Settings>>>,
+}
+
+impl DiscoveryLoop {
+    pub fn new(
+        targets: Vec<TargetDiscovery>,
+        interval: Duration,
+        status: CollectionStatus,
+    ) -> Self {
+        Self {
+            targets,
+            interval,
+            status,
+            config_updates: None,
+        }
+    }
+
+    pub fn with_config_updates(mut self, updates: watch::Receiver<Arc<Settings>>) -> Self {
+        self.config_updates = Some(updates);
+        self
+    }
+
+    /// 모든 대상을 동시에 한 번 조회합니다.
+    pub async fn discover_all(&mut self) {
+        let status = &self.status;
+        join_all(
+            self.targets
+                .iter_mut()
+                .map(|target| target.discover(status)),
+        )
+        .await;
+    }
+
+    /// 새 설정의 대상 필터를 적용합니다.
+    fn apply_config_updates(&mut self) {
+        let Some(updates) = self.config_updates.as_mut() else {
+            return;
+        };
+        let settings = Arc::clone(&updates.borrow_and_update());
+
+        // Settings::new에서 검증을 통과한 설정만 전달됩니다
+        if let Ok(filter) = settings.target.tag_filter() {
+            for target in &mut self.targets {
+                target.rds_manager.set_target_filter(filter.clone());
+            }
+        }
+    }
+
+    /// 종료 신호를 받을 때까지 `interval`마다, 그리고 설정이 바뀔 때마다 대상을 조회합니다.
+    /// 첫 조회는 호출 전에 [`DiscoveryLoop::discover_all`]로 실행합니다.
+    pub async fn run(&mut self, mut shutdown: watch::Receiver<bool>) {
+        let interval = std::time::Duration::from_secs(self.interval.num_seconds() as u64);
+
+        while !*shutdown.borrow() {
+            tokio::select! {
+                _ = tokio::time::sleep(interval) => {}
+                _ = config_changed(&mut self.config_updates) => {
+                    info!("설정 변경으로 대상을 다시 조회합니다");
+                    self.apply_config_updates();
+                }
+                // 송신 측이 사라진 경우도 종료로 봅니다
+                _ = shutdown.changed() => break,
+            }
+
+            self.discover_all().await;
+        }
+
+        info!("대상 조회 중지");
+    }
+}
+
+/// 설정이 바뀌면 완료됩니다. 설정 채널이 없거나 닫혔으면 완료되지 않습니다.
+async fn config_changed(updates: &mut Option<watch::Receiver<Arc<Settings>>>) {
+    match updates {
+        Some(updates) => {
+            if updates.changed().await.is_err() {
+                std::future::pending::<()>().await;
+            }
+        }
+        None => std::future::pending().await,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::aws::rds::RdsConfig;
+    use crate::aws::test_util::ReplayConnector;
+    use aws_sdk_rds::config::{Config, Credentials, Region};
+    use aws_sdk_rds::Client;
+
+    fn instances_response(ids: &[&str]) -> String {
+        let instances: String = ids
+            .iter()
+            .map(|id| {
+                format!(
+                    "<DBInstance><DBInstanceIdentifier>{id}</DBInstanceIdentifier>\
+                     <DBInstanceArn>arn:aws:rds:ap-northeast-2:123456789012:db:{id}</DBInstanceArn>\
+                     <Engine>mysql</Engine>\
+                     <TagList><Tag><Key>env</Key><Value>prd</Value></Tag></TagList></DBInstance>"
+                )
+            })
+            .collect();
+        format!(
+            r#"<DescribeDBInstancesResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <DescribeDBInstancesResult><DBInstances>{}</DBInstances></DescribeDBInstancesResult>
+</DescribeDBInstancesResponse>"#,
+            instances
+        )
+    }
+
+    const EMPTY_CLUSTERS: &str = r#"<DescribeDBClustersResponse xmlns="http://rds.amazonaws.com/doc/2014-10-31/">
+  <DescribeDBClustersResult><DBClusters/></DescribeDBClustersResult>
+</DescribeDBClustersResponse>"#;
+
+    #[tokio::test]
+    async fn test_discovery_publishes_targets_and_counts_changes() {
+        let connector = ReplayConnector::new([
+            instances_response(&["db-1", "db-2"]),
+            EMPTY_CLUSTERS.to_string(),
+            instances_response(&["db-2", "db-3", "db-4"]),
+            EMPTY_CLUSTERS.to_string(),
+        ]);
+        let client = Client::from_conf(
+            Config::builder()
+                .behavior_version_latest()
+                .region(Region::new("ap-northeast-2"))
+                .credentials_provider(Credentials::new("test", "test", None, None, "test"))
+                .http_client(connector.http_client())
+                .build(),
+        );
+        let rds_manager = RdsInstanceManager::new(
+            client,
+            RdsConfig {
+                // 두 번째 조회가 캐시가 아닌 새 응답을 사용하도록 캐시를 끕니다
+                cache_ttl: std::time::Duration::ZERO,
+                retry_delay: std::time::Duration::from_millis(1),
+                ..Default::default()
+            },
+        );
+        let (mut discovery, receiver) =
+            TargetDiscovery::new("", "discovery-test-region", rds_manager);
+        let status = CollectionStatus::default();
+        let changes = |change: &str| {
+            TARGETS_CHANGED
+                .with_label_values(&["", "discovery-test-region", "instance", change])
+                .get()
+        };
+
+        assert!(receiver.borrow().discovered_at.is_none());
+
+        discovery.discover(&status).await;
+        assert_eq!(receiver.borrow().instances.len(), 2);
+        // 첫 조회는 변경으로 세지 않습니다
+        assert_eq!(changes("added"), 0);
+
+        discovery.discover(&status).await;
+        let ids: Vec<_> = receiver
+            .borrow()
+            .instances
+            .iter()
+            .filter_map(|instance| instance.db_instance_identifier().map(String::from))
+            .collect();
+        assert_eq!(ids, vec!["db-2", "db-3", "db-4"]);
+        assert_eq!(changes("added"), 2);
+        assert_eq!(changes("removed"), 1);
+
+        // 조회에 실패하면 이전 대상을 유지합니다
+        discovery.discover(&status).await;
+        assert_eq!(receiver.borrow().instances.len(), 3);
+    }
+}
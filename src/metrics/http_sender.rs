@@ -0,0 +1,180 @@
+// src/metrics/http_sender.rs
+use crate::config::HttpPublisherSettings;
+use crate::exporter_metrics::PUBLISHER_REQUESTS;
+use reqwest::header::HeaderMap;
+use reqwest::{Client, Method, StatusCode};
+use std::time::Duration;
+use thiserror::Error;
+use tokio::time::sleep;
+use tracing::{info, warn};
+
+#[derive(Error, Debug)]
+pub enum SendError {
+    #[error("요청 거부 ({status}): {body}")]
+    Rejected { status: StatusCode, body: String },
+
+    #[error("재시도 횟수 초과: {0}")]
+    RetryExhausted(String),
+
+    #[error("HTTP 클라이언트 생성 실패: {0}")]
+    Client(#[from] reqwest::Error),
+}
+
+/// 푸시 방식 발행자의 HTTP 인증
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HttpAuth {
+    Basic { username: String, password: String },
+    Bearer(String),
+}
+
+#[derive(Debug, Clone)]
+pub struct HttpSenderConfig {
+    pub url: String,
+    pub auth: Option<HttpAuth>,
+    /// 최대 시도 횟수
+    pub max_retries: u32,
+    /// 첫 재시도 전 대기 시간 (이후 두 배씩 증가)
+    pub retry_delay: Duration,
+    /// 요청 하나의 제한 시간
+    pub timeout: Duration,
+}
+
+impl HttpSenderConfig {
+    pub fn from_settings(settings: &HttpPublisherSettings, url: &str) -> Self {
+        let auth = match (&settings.basic_auth, &settings.bearer_token) {
+            (Some(basic), _) => Some(HttpAuth::Basic {
+                username: basic.username.clone(),
+                password: basic.password.clone(),
+            }),
+            (None, Some(token)) => Some(HttpAuth::Bearer(token.clone())),
+            (None, None) => None,
+        };
+
+        Self {
+            url: url.to_string(),
+            auth,
+            max_retries: settings.max_retries,
+            retry_delay: Duration::from_secs(settings.retry_delay),
+            timeout: Duration::from_secs(settings.timeout),
+        }
+    }
+}
+
+/// 푸시 방식 발행자가 공유하는 HTTP 전송기
+pub struct HttpSender {
+    /// 자체 메트릭의 `publisher` 레이블
+    publisher: &'static str,
+    client: Client,
+    config: HttpSenderConfig,
+}
+
+impl HttpSender {
+    pub fn new(publisher: &'static str, config: HttpSenderConfig) -> Result<Self, SendError> {
+        let client = Client::builder()
+            .timeout(config.timeout)
+            .user_agent(concat!("rds_exporter/", env!("CARGO_PKG_VERSION")))
+            .build()?;
+
+        Ok(Self {
+            publisher,
+            client,
+            config,
+        })
+    }
+
+    /// 본문을 `url`로 전송하고 성공 응답의 본문을 반환합니다.
+    ///
+    /// 연결 오류, 시간 초과, 429, 5xx는 지수 백오프로 재시도하고,
+    /// 그 밖의 4xx는 다시 보내도 성공할 수 없으므로 바로 [`SendError::Rejected`]를 반환합니다.
+    pub async fn send(
+        &self,
+        method: Method,
+        headers: HeaderMap,
+        body: Vec<u8>,
+    ) -> Result<Vec<u8>, SendError> {
+        self.send_to(&self.config.url, method, headers, body).await
+    }
+
+    /// [`HttpSender::send`]와 같지만 설정의 `url` 대신 `url`로 전송합니다.
+    pub async fn send_to(
+        &self,
+        url: &str,
+        method: Method,
+        headers: HeaderMap,
+        body: Vec<u8>,
+    ) -> Result<Vec<u8>, SendError> {
+        let mut attempts = 0;
+        let mut last_error = String::new();
+        let mut delay = self.config.retry_delay;
+
+        while attempts < self.config.max_retries {
+            let mut request = self
+                .client
+                .request(method.clone(), url)
+                .headers(headers.clone())
+                .body(body.clone());
+            request = match &self.config.auth {
+                Some(HttpAuth::Basic { username, password }) => {
+                    request.basic_auth(username, Some(password))
+                }
+                Some(HttpAuth::Bearer(token)) => request.bearer_auth(token),
+                None => request,
+            };
+
+            match request.send().await {
+                Ok(response) if response.status().is_success() => {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[self.publisher, "success"])
+                        .inc();
+                    if attempts > 0 {
+                        info!(
+                            "{} 재시도 성공 (시도 횟수: {})",
+                            self.publisher,
+                            attempts + 1
+                        );
+                    }
+                    return Ok(response
+                        .bytes()
+                        .await
+                        .map(|body| body.to_vec())
+                        .unwrap_or_default());
+                }
+                Ok(response)
+                    if response.status() != StatusCode::TOO_MANY_REQUESTS
+                        && response.status().is_client_error() =>
+                {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[self.publisher, "rejected"])
+                        .inc();
+                    let status = response.status();
+                    let body = response.text().await.unwrap_or_default();
+                    return Err(SendError::Rejected { status, body });
+                }
+                Ok(response) => {
+                    last_error = format!("HTTP {}", response.status());
+                }
+                Err(e) => {
+                    last_error = e.to_string();
+                }
+            }
+
+            PUBLISHER_REQUESTS
+                .with_label_values(&[self.publisher, "error"])
+                .inc();
+            warn!(
+                "{} 전송 실패 (시도 횟수: {}): {}",
+                self.publisher,
+                attempts + 1,
+                last_error
+            );
+            attempts += 1;
+
+            if attempts < self.config.max_retries {
+                sleep(delay).await;
+                delay *= 2;
+            }
+        }
+
+        Err(SendError::RetryExhausted(last_error))
+    }
+}
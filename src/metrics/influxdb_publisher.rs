@@ -0,0 +1,438 @@
+// src/metrics/influxdb_publisher.rs
+use crate::config::{InfluxDbSettings, InfluxPrecision, StatStyle};
+use crate::exporter_metrics::PUBLISHER_DROPPED_SAMPLES;
+use crate::metrics::collector::{MetricKind, MetricPoint, MetricPublisher};
+use crate::metrics::http_sender::{HttpSender, HttpSenderConfig, SendError};
+use crate::metrics::prometheus_publisher::metric_name;
+use crate::metrics::send_queue::SendQueue;
+use crate::metrics::udp_sender::{UdpSendError, UdpSender};
+use async_trait::async_trait;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, HeaderValue, AUTHORIZATION, CONTENT_TYPE};
+use reqwest::Method;
+use std::sync::Arc;
+use thiserror::Error;
+use tracing::{debug, error, warn};
+
+const PUBLISHER: &str = "influxdb";
+
+#[derive(Error, Debug)]
+pub enum InfluxDbError {
+    #[error(transparent)]
+    Http(#[from] SendError),
+
+    #[error(transparent)]
+    Udp(#[from] UdpSendError),
+
+    #[error("UDP 소켓 생성 실패: {0}")]
+    Socket(#[from] std::io::Error),
+
+    #[error("잘못된 토큰입니다")]
+    InvalidToken,
+}
+
+impl InfluxPrecision {
+    fn timestamp(&self, time: &DateTime<Utc>) -> i64 {
+        match self {
+            InfluxPrecision::Ns => time.timestamp_nanos_opt().unwrap_or_default(),
+            InfluxPrecision::Us => time.timestamp_micros(),
+            InfluxPrecision::Ms => time.timestamp_millis(),
+            InfluxPrecision::S => time.timestamp(),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub enum InfluxTransport {
+    /// v2 write API (`/api/v2/write`). `url`에는 org, bucket, precision 쿼리가 포함됩니다.
+    Http {
+        http: HttpSenderConfig,
+        /// InfluxDB API 토큰 (`Authorization: Token ...`)
+        token: Option<String>,
+    },
+    /// InfluxDB UDP 수신기 (`호스트:포트`). 데이터베이스는 수신 측 설정을 따릅니다.
+    Udp {
+        address: String,
+        max_packet_size: usize,
+    },
+}
+
+#[derive(Debug, Clone)]
+pub struct InfluxDbConfig {
+    pub transport: InfluxTransport,
+    pub precision: InfluxPrecision,
+    /// 요청 하나에 담을 최대 줄 수
+    pub batch_size: usize,
+    /// 전송을 기다리는 줄의 최대 수. 넘치면 가장 오래된 줄부터 버립니다.
+    pub queue_capacity: usize,
+    pub stat_style: StatStyle,
+}
+
+impl InfluxDbConfig {
+    pub fn from_settings(settings: &InfluxDbSettings, stat_style: StatStyle) -> Self {
+        let transport = match settings.udp_address() {
+            Some(address) => InfluxTransport::Udp {
+                address: address.to_string(),
+                max_packet_size: settings.max_packet_size,
+            },
+            None => InfluxTransport::Http {
+                // Settings::new에서 검증을 통과한 주소입니다
+                http: HttpSenderConfig::from_settings(
+                    &settings.http,
+                    &settings
+                        .write_url()
+                        .unwrap_or_else(|_| settings.url.clone()),
+                ),
+                token: settings.token.clone(),
+            },
+        };
+
+        Self {
+            transport,
+            precision: settings.precision,
+            batch_size: settings.batch_size,
+            queue_capacity: settings.queue_capacity,
+            stat_style,
+        }
+    }
+}
+
+enum Transport {
+    Http {
+        sender: HttpSender,
+        headers: HeaderMap,
+    },
+    Udp(UdpSender),
+}
+
+/// `MetricPoint`를 InfluxDB line protocol로 기록하는 발행자
+///
+/// 메트릭 패밀리(`/metrics`의 메트릭 이름)마다 measurement 하나를 쓰며, 값은 `value` 필드에,
+/// `additional_tags`와 `stat`은 태그에 담습니다. 타임스탬프는 CloudWatch 데이터 포인트 시각입니다.
+/// 줄은 [`SendQueue`]를 거쳐 `batch_size`개씩 HTTP 요청 하나 또는 `max_packet_size` 이하의 UDP 패킷들로 기록되며,
+/// UDP로 보내지 못한 줄은 다시 보내지 않고 버립니다.
+#[derive(Clone)]
+pub struct InfluxDbPublisher {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    transport: Transport,
+    config: InfluxDbConfig,
+    queue: SendQueue<String>,
+}
+
+impl InfluxDbPublisher {
+    pub fn new(config: InfluxDbConfig) -> Result<Self, InfluxDbError> {
+        let transport = match &config.transport {
+            InfluxTransport::Http { http, token } => {
+                let mut headers = HeaderMap::new();
+                headers.insert(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("text/plain; charset=utf-8"),
+                );
+                if let Some(token) = token {
+                    headers.insert(
+                        AUTHORIZATION,
+                        HeaderValue::from_str(&format!("Token {}", token))
+                            .map_err(|_| InfluxDbError::InvalidToken)?,
+                    );
+                }
+                Transport::Http {
+                    sender: HttpSender::new(PUBLISHER, http.clone())?,
+                    headers,
+                }
+            }
+            InfluxTransport::Udp {
+                address,
+                max_packet_size,
+            } => Transport::Udp(UdpSender::connect(PUBLISHER, address, *max_packet_size)?),
+        };
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                transport,
+                queue: SendQueue::new(PUBLISHER, config.queue_capacity),
+                config,
+            }),
+        })
+    }
+}
+
+impl Inner {
+    /// 데이터 포인트 하나를 line protocol 한 줄로 바꿉니다.
+    /// line protocol은 NaN과 무한대를 표현할 수 없으므로 이런 값은 `None`입니다.
+    fn line(&self, metric: &MetricPoint) -> Option<String> {
+        if !metric.value.is_finite() {
+            return None;
+        }
+
+        let mut tags: Vec<(&str, &str)> = metric
+            .additional_tags
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        if metric.kind == MetricKind::CloudWatch
+            && self.config.stat_style == StatStyle::Label
+            && !metric.stat.is_empty()
+        {
+            tags.push(("stat", &metric.stat));
+        }
+        // InfluxDB 권장 사항대로 태그를 키 순서로 씁니다
+        tags.sort();
+
+        let mut line = escape(&metric_name(metric, self.config.stat_style), &[',', ' ']);
+        for (key, value) in tags {
+            line.push(',');
+            line.push_str(&escape(key, &[',', '=', ' ']));
+            line.push('=');
+            line.push_str(&escape(value, &[',', '=', ' ']));
+        }
+        line.push_str(&format!(
+            " value={:?} {}",
+            metric.value,
+            self.config.precision.timestamp(&metric.timestamp)
+        ));
+        Some(line)
+    }
+
+    /// 배치 하나를 기록합니다. 거부된 배치와 UDP로 보내지 못한 줄은 버리고,
+    /// HTTP에서 다시 보낼 수 있는 실패만 오류로 반환합니다.
+    async fn write(&self, lines: Vec<String>) -> Result<(), InfluxDbError> {
+        let result = match &self.transport {
+            Transport::Http { sender, headers } => sender
+                .send(Method::POST, headers.clone(), lines.join("\n").into_bytes())
+                .await
+                .map(|_| ())
+                .map_err(InfluxDbError::from),
+            Transport::Udp(sender) => sender.send_lines(&lines).await.map_err(Into::into),
+        };
+
+        let dropped = match &result {
+            Ok(()) => {
+                debug!("InfluxDB 기록 완료: {} 줄", lines.len());
+                return Ok(());
+            }
+            // UDP는 보내지 못한 패킷의 줄만 버려집니다
+            Err(InfluxDbError::Udp(e)) => e.lines,
+            Err(InfluxDbError::Http(SendError::Rejected { .. })) => lines.len(),
+            Err(_) => return result,
+        };
+        PUBLISHER_DROPPED_SAMPLES
+            .with_label_values(&[PUBLISHER])
+            .inc_by(dropped as u64);
+        if let Err(e) = result {
+            error!("InfluxDB 기록 실패, {} 줄을 버립니다: {}", dropped, e);
+        }
+        Ok(())
+    }
+
+    async fn drain(&self) -> Result<(), InfluxDbError> {
+        self.queue
+            .drain(self.config.batch_size, |batch| self.write(batch))
+            .await
+    }
+}
+
+#[async_trait]
+impl MetricPublisher for InfluxDbPublisher {
+    async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+        let lines: Vec<String> = metrics.iter().filter_map(|m| self.inner.line(m)).collect();
+        if lines.len() < metrics.len() {
+            debug!(
+                "InfluxDB에 기록할 수 없는 값(NaN, 무한대) {} 개를 건너뜁니다",
+                metrics.len() - lines.len()
+            );
+        }
+        self.inner.queue.push(lines);
+
+        let inner = Arc::clone(&self.inner);
+        tokio::spawn(async move {
+            if let Err(e) = inner.drain().await {
+                warn!("InfluxDB 기록 실패, 다음 주기에 다시 시도합니다: {}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+        Vec::new()
+    }
+
+    async fn flush(&self) -> anyhow::Result<()> {
+        self.inner.drain().await?;
+        Ok(())
+    }
+}
+
+/// line protocol 이름과 태그에서 특수 문자를 백슬래시로 이스케이프합니다.
+/// 개행은 이스케이프할 수 없으므로 (이스케이프한) 공백으로 바꿉니다.
+fn escape(value: &str, special: &[char]) -> String {
+    let mut escaped = String::with_capacity(value.len());
+    for c in value.chars() {
+        match c {
+            '\n' | '\r' => escaped.push_str("\\ "),
+            c if special.contains(&c) => {
+                escaped.push('\\');
+                escaped.push(c);
+            }
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PublishersSettings;
+    use crate::metrics::test_util::{http_config, instance_point as point, HttpReceiver};
+
+    fn config(transport: InfluxTransport) -> InfluxDbConfig {
+        InfluxDbConfig {
+            transport,
+            precision: InfluxPrecision::Ns,
+            batch_size: 2,
+            queue_capacity: 100,
+            stat_style: StatStyle::Label,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_line_protocol() {
+        let publisher = InfluxDbPublisher::new(config(InfluxTransport::Udp {
+            address: "127.0.0.1:8089".to_string(),
+            max_packet_size: 1432,
+        }))
+        .unwrap();
+
+        assert_eq!(
+            publisher.inner.line(&point("db-1", 12.5)).unwrap(),
+            "rds_cpuutilization,db_instance_identifier=db-1,engine=mysql,stat=Average \
+             value=12.5 1735689600000000000"
+        );
+        // 정수 값도 float 필드로 씁니다
+        assert!(publisher
+            .inner
+            .line(&point("db-1", 3.0))
+            .unwrap()
+            .contains(" value=3.0 "));
+        assert_eq!(
+            publisher.inner.line(&point("db 1,a=b", 1.0)).unwrap(),
+            "rds_cpuutilization,db_instance_identifier=db\\ 1\\,a\\=b,engine=mysql,stat=Average \
+             value=1.0 1735689600000000000"
+        );
+        assert!(publisher.inner.line(&point("db-1", f64::NAN)).is_none());
+
+        let seconds = InfluxDbPublisher::new(InfluxDbConfig {
+            precision: InfluxPrecision::S,
+            ..publisher.inner.config.clone()
+        })
+        .unwrap();
+        assert!(seconds
+            .inner
+            .line(&point("db-1", 1.0))
+            .unwrap()
+            .ends_with(" 1735689600"));
+    }
+
+    #[tokio::test]
+    async fn test_writes_batches_to_v2_api() {
+        let (receiver, url) = HttpReceiver::start(&[], 204);
+        let publisher = InfluxDbPublisher::new(config(InfluxTransport::Http {
+            http: http_config(format!(
+                "{}/api/v2/write?org=dba&bucket=rds&precision=ns",
+                url
+            )),
+            token: Some("secret".to_string()),
+        }))
+        .unwrap();
+        publisher
+            .publish(vec![
+                point("db-1", 1.0),
+                point("db-2", 2.0),
+                point("db-3", 3.0),
+            ])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+
+        let requests = receiver.requests();
+        assert_eq!(requests.len(), 2);
+        let request = &requests[0];
+        assert_eq!(request.method, "POST");
+        assert_eq!(request.path, "/api/v2/write");
+        assert_eq!(request.query["org"], "dba");
+        assert_eq!(request.query["bucket"], "rds");
+        assert_eq!(request.query["precision"], "ns");
+        assert_eq!(request.headers["authorization"], "Token secret");
+        assert_eq!(request.text().lines().count(), 2);
+        assert!(requests[1]
+            .text()
+            .starts_with("rds_cpuutilization,db_instance_identifier=db-3,"));
+    }
+
+    #[tokio::test]
+    async fn test_writes_packets_over_udp() {
+        let receiver = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let line_length =
+            "rds_cpuutilization,db_instance_identifier=db-1,engine=mysql,stat=Average \
+                           value=1.0 1735689600000000000"
+                .len();
+        let publisher = InfluxDbPublisher::new(InfluxDbConfig {
+            batch_size: 100,
+            ..config(InfluxTransport::Udp {
+                address: receiver.local_addr().unwrap().to_string(),
+                // 두 줄까지 한 패킷에 담깁니다
+                max_packet_size: line_length * 2 + 1,
+            })
+        })
+        .unwrap();
+
+        publisher
+            .publish(vec![
+                point("db-1", 1.0),
+                point("db-2", 2.0),
+                point("db-3", 3.0),
+            ])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+
+        let mut buffer = [0u8; 2048];
+        let mut packets = Vec::new();
+        for _ in 0..2 {
+            let size = receiver.recv(&mut buffer).await.unwrap();
+            packets.push(String::from_utf8(buffer[..size].to_vec()).unwrap());
+        }
+        let lines: Vec<_> = packets.iter().map(|p| p.lines().count()).collect();
+        assert_eq!(lines, vec![2, 1]);
+    }
+
+    #[test]
+    fn test_config_from_settings() {
+        let publishers = PublishersSettings::example();
+        let mut settings = publishers.influxdb.unwrap();
+        let config = InfluxDbConfig::from_settings(&settings, StatStyle::Label);
+        assert_eq!(config.precision, InfluxPrecision::Ms);
+        match &config.transport {
+            InfluxTransport::Http { http, token } => {
+                assert_eq!(
+                    http.url,
+                    "http://influxdb:8086/api/v2/write?precision=ms&bucket=rds&org=dba+team"
+                );
+                assert_eq!(http.auth, None);
+                assert_eq!(token.as_deref(), Some("secret"));
+            }
+            other => panic!("HTTP 전송이 아닙니다: {:?}", other),
+        }
+
+        settings.url = "udp://influxdb:8089".to_string();
+        assert!(matches!(
+            InfluxDbConfig::from_settings(&settings, StatStyle::Label).transport,
+            InfluxTransport::Udp { ref address, max_packet_size: 1432 } if address == "influxdb:8089"
+        ));
+    }
+}
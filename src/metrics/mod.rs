@@ -1,2 +1,15 @@
+pub mod catalog;
 pub mod collector;
+pub mod discovery;
+pub mod http_sender;
+pub mod influxdb_publisher;
+pub mod otlp_publisher;
 pub mod prometheus_publisher;
+pub mod pushgateway_publisher;
+pub mod remote_write_publisher;
+pub mod send_queue;
+pub mod statsd_publisher;
+pub mod status;
+#[cfg(test)]
+pub mod test_util;
+pub mod udp_sender;
@@ -0,0 +1,750 @@
+// src/metrics/otlp_publisher.rs
+use crate::config::{OtlpProtocol, OtlpSettings, StatStyle};
+use crate::exporter_metrics::{PUBLISHER_DROPPED_SAMPLES, PUBLISHER_REQUESTS};
+use crate::metrics::collector::{MetricKind, MetricPoint, MetricPublisher};
+use crate::metrics::http_sender::{HttpAuth, HttpSender, HttpSenderConfig, SendError};
+use crate::metrics::prometheus_publisher::metric_name;
+use crate::metrics::send_queue::SendQueue;
+use async_trait::async_trait;
+use base64::Engine;
+use opentelemetry_proto::tonic::collector::metrics::v1::metrics_service_client::MetricsServiceClient;
+use opentelemetry_proto::tonic::collector::metrics::v1::{
+    ExportMetricsServiceRequest, ExportMetricsServiceResponse,
+};
+use opentelemetry_proto::tonic::common::v1::{any_value, AnyValue, InstrumentationScope, KeyValue};
+use opentelemetry_proto::tonic::metrics::v1::{
+    metric, number_data_point, Gauge, Metric, NumberDataPoint, ResourceMetrics, ScopeMetrics,
+};
+use opentelemetry_proto::tonic::resource::v1::Resource;
+use prost::Message;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, CONTENT_TYPE};
+use reqwest::Method;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::time::sleep;
+use tonic::metadata::{AsciiMetadataKey, AsciiMetadataValue, MetadataMap};
+use tonic::transport::{Channel, ClientTlsConfig, Endpoint};
+use tonic::Code;
+use tracing::{debug, error, info, warn};
+
+const PUBLISHER: &str = "otlp";
+
+/// 리소스 속성으로 옮기는 레이블. 나머지 레이블은 데이터 포인트 속성이 됩니다.
+const RESOURCE_LABELS: [&str; 2] = ["account_id", "region"];
+
+/// 리소스 속성 목록. 같은 값을 가진 데이터 포인트를 하나의 `ResourceMetrics`로 묶습니다.
+type ResourceKey = Vec<(&'static str, String)>;
+
+#[derive(Error, Debug)]
+pub enum OtlpError {
+    #[error(transparent)]
+    Http(#[from] SendError),
+
+    #[error("gRPC 요청 거부 ({code:?}): {message}")]
+    Rejected { code: Code, message: String },
+
+    #[error("재시도 횟수 초과: {0}")]
+    RetryExhausted(String),
+
+    #[error("OTLP 엔드포인트 설정 오류: {0}")]
+    Endpoint(#[from] tonic::transport::Error),
+
+    #[error("잘못된 헤더: {0}")]
+    InvalidHeader(String),
+
+    #[error("응답 해석 실패: {0}")]
+    Decode(#[from] prost::DecodeError),
+}
+
+#[derive(Debug, Clone)]
+pub struct OtlpConfig {
+    pub protocol: OtlpProtocol,
+    /// `url`은 gRPC면 collector 주소, HTTP면 `/v1/metrics`까지 포함한 전체 주소
+    pub http: HttpSenderConfig,
+    /// 모든 요청에 붙일 헤더 (gRPC는 메타데이터)
+    pub headers: Vec<(String, String)>,
+    /// 요청 하나에 담을 최대 데이터 포인트 수
+    pub batch_size: usize,
+    /// 전송을 기다리는 데이터 포인트의 최대 수. 넘치면 가장 오래된 것부터 버립니다.
+    pub queue_capacity: usize,
+    pub stat_style: StatStyle,
+}
+
+impl OtlpConfig {
+    pub fn from_settings(settings: &OtlpSettings, stat_style: StatStyle) -> Self {
+        Self {
+            protocol: settings.protocol,
+            http: HttpSenderConfig::from_settings(&settings.http, &settings.endpoint),
+            headers: settings
+                .headers
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            batch_size: settings.batch_size,
+            queue_capacity: settings.queue_capacity,
+            stat_style,
+        }
+    }
+}
+
+enum Transport {
+    Grpc {
+        client: MetricsServiceClient<Channel>,
+        metadata: MetadataMap,
+    },
+    Http {
+        sender: HttpSender,
+        headers: HeaderMap,
+    },
+}
+
+/// `MetricPoint`를 OTLP gauge로 OpenTelemetry collector에 보내는 발행자
+///
+/// `region`, `account_id`는 리소스 속성(`cloud.region`, `cloud.account.id`)으로 옮기고,
+/// `engine`은 리소스 속성 `db.system`으로 바꾸되 `aurora-mysql`과 `mysql`처럼 `db.system`에서
+/// 구분되지 않는 엔진을 나누도록 데이터 포인트 속성에도 남깁니다. 나머지 레이블과 `stat`은
+/// 데이터 포인트 속성으로 보냅니다.
+/// 데이터 포인트는 [`SendQueue`]를 거쳐 `batch_size`개씩 한 요청으로 전송됩니다.
+#[derive(Clone)]
+pub struct OtlpPublisher {
+    inner: Arc<Inner>,
+}
+
+struct Inner {
+    transport: Transport,
+    config: OtlpConfig,
+    queue: SendQueue<MetricPoint>,
+}
+
+impl OtlpPublisher {
+    pub fn new(config: OtlpConfig) -> Result<Self, OtlpError> {
+        let transport = match config.protocol {
+            OtlpProtocol::Grpc => {
+                let mut endpoint = Endpoint::from_shared(config.http.url.clone())?
+                    .timeout(config.http.timeout)
+                    .connect_timeout(config.http.timeout)
+                    .user_agent(concat!("rds_exporter/", env!("CARGO_PKG_VERSION")))?;
+                if config.http.url.starts_with("https://") {
+                    endpoint = endpoint.tls_config(ClientTlsConfig::new().with_native_roots())?;
+                }
+
+                Transport::Grpc {
+                    client: MetricsServiceClient::new(endpoint.connect_lazy()),
+                    metadata: grpc_metadata(&config)?,
+                }
+            }
+            OtlpProtocol::HttpProtobuf => {
+                let mut headers = http_headers(&config.headers)?;
+                headers.insert(
+                    CONTENT_TYPE,
+                    HeaderValue::from_static("application/x-protobuf"),
+                );
+                Transport::Http {
+                    sender: HttpSender::new(PUBLISHER, config.http.clone())?,
+                    headers,
+                }
+            }
+        };
+
+        Ok(Self {
+            inner: Arc::new(Inner {
+                transport,
+                queue: SendQueue::new(PUBLISHER, config.queue_capacity),
+                config,
+            }),
+        })
+    }
+}
+
+impl Inner {
+    /// 같은 리소스(리전, 계정, DB 엔진)의 데이터 포인트를 묶고, 그 안에서 메트릭 이름별로 묶습니다.
+    fn export_request(&self, metrics: &[MetricPoint]) -> ExportMetricsServiceRequest {
+        let mut resources: BTreeMap<ResourceKey, BTreeMap<String, Vec<NumberDataPoint>>> =
+            BTreeMap::new();
+
+        for metric in metrics {
+            let tag = |name: &str| {
+                metric
+                    .additional_tags
+                    .get(name)
+                    .map(String::as_str)
+                    .unwrap_or_default()
+            };
+            let mut resource = vec![("cloud.provider", "aws".to_string())];
+            for (key, value) in [
+                ("cloud.account.id", tag("account_id").to_string()),
+                ("cloud.region", tag("region").to_string()),
+                ("db.system", db_system(tag("engine")).to_string()),
+            ] {
+                if !value.is_empty() {
+                    resource.push((key, value));
+                }
+            }
+
+            let mut attributes: Vec<(&str, &str)> = metric
+                .additional_tags
+                .iter()
+                .filter(|(name, value)| {
+                    !value.is_empty() && !RESOURCE_LABELS.contains(&name.as_str())
+                })
+                .map(|(name, value)| (name.as_str(), value.as_str()))
+                .collect();
+            if metric.kind == MetricKind::CloudWatch
+                && self.config.stat_style == StatStyle::Label
+                && !metric.stat.is_empty()
+            {
+                attributes.push(("stat", &metric.stat));
+            }
+            attributes.sort();
+
+            resources
+                .entry(resource)
+                .or_default()
+                .entry(metric_name(metric, self.config.stat_style))
+                .or_default()
+                .push(NumberDataPoint {
+                    attributes: attributes
+                        .into_iter()
+                        .map(|(key, value)| key_value(key, value))
+                        .collect(),
+                    time_unix_nano: metric
+                        .timestamp
+                        .timestamp_nanos_opt()
+                        .unwrap_or_default()
+                        .max(0) as u64,
+                    value: Some(number_data_point::Value::AsDouble(metric.value)),
+                    ..Default::default()
+                });
+        }
+
+        ExportMetricsServiceRequest {
+            resource_metrics: resources
+                .into_iter()
+                .map(|(resource, metrics)| ResourceMetrics {
+                    resource: Some(Resource {
+                        attributes: resource
+                            .iter()
+                            .map(|(key, value)| key_value(key, value))
+                            .collect(),
+                        ..Default::default()
+                    }),
+                    scope_metrics: vec![ScopeMetrics {
+                        scope: Some(InstrumentationScope {
+                            name: "rds_exporter".to_string(),
+                            version: env!("CARGO_PKG_VERSION").to_string(),
+                            ..Default::default()
+                        }),
+                        metrics: metrics
+                            .into_iter()
+                            .map(|(name, data_points)| Metric {
+                                name,
+                                data: Some(metric::Data::Gauge(Gauge { data_points })),
+                                ..Default::default()
+                            })
+                            .collect(),
+                        ..Default::default()
+                    }],
+                    ..Default::default()
+                })
+                .collect(),
+        }
+    }
+
+    async fn export(
+        &self,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<ExportMetricsServiceResponse, OtlpError> {
+        match &self.transport {
+            Transport::Http { sender, headers } => {
+                let body = sender
+                    .send(Method::POST, headers.clone(), request.encode_to_vec())
+                    .await?;
+                Ok(ExportMetricsServiceResponse::decode(body.as_slice())?)
+            }
+            Transport::Grpc { client, metadata } => {
+                self.export_grpc(client.clone(), metadata, request).await
+            }
+        }
+    }
+
+    /// gRPC로 전송합니다. OTLP 명세에서 재시도 가능한 상태 코드만 지수 백오프로 재시도합니다.
+    async fn export_grpc(
+        &self,
+        mut client: MetricsServiceClient<Channel>,
+        metadata: &MetadataMap,
+        request: ExportMetricsServiceRequest,
+    ) -> Result<ExportMetricsServiceResponse, OtlpError> {
+        let mut attempts = 0;
+        let mut last_error = String::new();
+        let mut delay = self.config.http.retry_delay;
+
+        while attempts < self.config.http.max_retries {
+            let mut grpc_request = tonic::Request::new(request.clone());
+            *grpc_request.metadata_mut() = metadata.clone();
+
+            match client.export(grpc_request).await {
+                Ok(response) => {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[PUBLISHER, "success"])
+                        .inc();
+                    if attempts > 0 {
+                        info!("{} 재시도 성공 (시도 횟수: {})", PUBLISHER, attempts + 1);
+                    }
+                    return Ok(response.into_inner());
+                }
+                Err(status) if !is_retryable(status.code()) => {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[PUBLISHER, "rejected"])
+                        .inc();
+                    return Err(OtlpError::Rejected {
+                        code: status.code(),
+                        message: status.message().to_string(),
+                    });
+                }
+                Err(status) => {
+                    last_error = format!("{:?}: {}", status.code(), status.message());
+                }
+            }
+
+            PUBLISHER_REQUESTS
+                .with_label_values(&[PUBLISHER, "error"])
+                .inc();
+            warn!(
+                "{} 전송 실패 (시도 횟수: {}): {}",
+                PUBLISHER,
+                attempts + 1,
+                last_error
+            );
+            attempts += 1;
+
+            if attempts < self.config.http.max_retries {
+                sleep(delay).await;
+                delay *= 2;
+            }
+        }
+
+        Err(OtlpError::RetryExhausted(last_error))
+    }
+
+    /// 배치 하나를 전송합니다. 거부된 배치는 버리고, 다시 보낼 수 있는 실패만 오류로 반환합니다.
+    async fn send(&self, batch: Vec<MetricPoint>) -> Result<(), OtlpError> {
+        let count = batch.len();
+        match self.export(self.export_request(&batch)).await {
+            Ok(response) => {
+                let rejected = response
+                    .partial_success
+                    .map(|partial| (partial.rejected_data_points, partial.error_message))
+                    .filter(|(rejected, _)| *rejected > 0);
+                if let Some((rejected, message)) = rejected {
+                    PUBLISHER_DROPPED_SAMPLES
+                        .with_label_values(&[PUBLISHER])
+                        .inc_by(rejected as u64);
+                    warn!(
+                        "OTLP collector가 데이터 포인트 {} 개를 거부했습니다: {}",
+                        rejected, message
+                    );
+                }
+                debug!("OTLP 전송 완료: {} 개", count);
+            }
+            // 다시 보내도 거부되거나 이미 전달된 배치는 버립니다
+            Err(
+                e @ (OtlpError::Rejected { .. }
+                | OtlpError::Http(SendError::Rejected { .. })
+                | OtlpError::Decode(_)),
+            ) => {
+                PUBLISHER_DROPPED_SAMPLES
+                    .with_label_values(&[PUBLISHER])
+                    .inc_by(count as u64);
+                error!(
+                    "OTLP 요청 거부, 데이터 포인트 {} 개를 버립니다: {}",
+                    count, e
+                );
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+
+    async fn drain(&self) -> Result<(), OtlpError> {
+        self.queue
+            .drain(self.config.batch_size, |batch| self.send(batch))
+            .await
+    }
+}
+
+#[async_trait]
+impl MetricPublisher for OtlpPublisher {
+    async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+        self.inner.queue.push(metrics);
+
+        let inner = Arc::clone(&self.inner);
+        tokio::spawn(async move {
+            if let Err(e) = inner.drain().await {
+                warn!("OTLP 전송 실패, 다음 주기에 다시 시도합니다: {}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+        Vec::new()
+    }
+
+    async fn flush(&self) -> anyhow::Result<()> {
+        self.inner.drain().await?;
+        Ok(())
+    }
+}
+
+/// RDS 엔진 이름을 OpenTelemetry 시맨틱 규약의 `db.system` 값으로 바꿉니다.
+fn db_system(engine: &str) -> &str {
+    match engine {
+        "" => "",
+        "mysql" | "aurora" | "aurora-mysql" => "mysql",
+        "postgres" | "aurora-postgresql" => "postgresql",
+        "mariadb" => "mariadb",
+        _ if engine.starts_with("oracle") => "oracle",
+        _ if engine.starts_with("sqlserver") => "mssql",
+        _ if engine.starts_with("db2") => "db2",
+        _ => "other_sql",
+    }
+}
+
+/// OTLP/gRPC 명세에서 재시도해도 되는 상태 코드
+fn is_retryable(code: Code) -> bool {
+    matches!(
+        code,
+        Code::Cancelled
+            | Code::DeadlineExceeded
+            | Code::ResourceExhausted
+            | Code::Aborted
+            | Code::OutOfRange
+            | Code::Unavailable
+            | Code::DataLoss
+    )
+}
+
+fn key_value(key: &str, value: &str) -> KeyValue {
+    KeyValue {
+        key: key.to_string(),
+        value: Some(AnyValue {
+            value: Some(any_value::Value::StringValue(value.to_string())),
+        }),
+    }
+}
+
+fn http_headers(headers: &[(String, String)]) -> Result<HeaderMap, OtlpError> {
+    let mut map = HeaderMap::new();
+    for (name, value) in headers {
+        map.insert(
+            HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| OtlpError::InvalidHeader(name.clone()))?,
+            HeaderValue::from_str(value).map_err(|_| OtlpError::InvalidHeader(name.clone()))?,
+        );
+    }
+    Ok(map)
+}
+
+/// 설정한 헤더와 인증 정보를 gRPC 메타데이터로 바꿉니다.
+fn grpc_metadata(config: &OtlpConfig) -> Result<MetadataMap, OtlpError> {
+    let mut entries = config.headers.clone();
+    match &config.http.auth {
+        Some(HttpAuth::Basic { username, password }) => entries.push((
+            "authorization".to_string(),
+            format!(
+                "Basic {}",
+                base64::engine::general_purpose::STANDARD
+                    .encode(format!("{}:{}", username, password))
+            ),
+        )),
+        Some(HttpAuth::Bearer(token)) => {
+            entries.push(("authorization".to_string(), format!("Bearer {}", token)))
+        }
+        None => {}
+    }
+
+    let mut metadata = MetadataMap::new();
+    for (name, value) in entries {
+        let key = AsciiMetadataKey::from_bytes(name.to_ascii_lowercase().as_bytes())
+            .map_err(|_| OtlpError::InvalidHeader(name.clone()))?;
+        let value: AsciiMetadataValue = value
+            .parse()
+            .map_err(|_| OtlpError::InvalidHeader(name.clone()))?;
+        metadata.insert(key, value);
+    }
+    Ok(metadata)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PublishersSettings;
+    use crate::metrics::test_util::{cpu_point, http_config, HttpReceiver};
+    use opentelemetry_proto::tonic::collector::metrics::v1::metrics_service_server::{
+        MetricsService, MetricsServiceServer,
+    };
+    use parking_lot::Mutex;
+    use std::collections::VecDeque;
+    use std::sync::Arc;
+
+    fn config(protocol: OtlpProtocol, url: String) -> OtlpConfig {
+        OtlpConfig {
+            protocol,
+            http: HttpSenderConfig {
+                auth: Some(HttpAuth::Bearer("secret".to_string())),
+                ..http_config(url)
+            },
+            headers: vec![("X-Scope-OrgID".to_string(), "dba".to_string())],
+            batch_size: 2,
+            queue_capacity: 100,
+            stat_style: StatStyle::Label,
+        }
+    }
+
+    fn point(instance: &str, engine: &str, region: &str, value: f64) -> MetricPoint {
+        cpu_point(
+            value,
+            &[
+                ("db_instance_identifier", instance),
+                ("engine", engine),
+                ("region", region),
+                ("account_id", ""),
+                ("class", ""),
+            ],
+        )
+    }
+
+    fn attributes(attributes: &[KeyValue]) -> Vec<(&str, &str)> {
+        attributes
+            .iter()
+            .map(|kv| match &kv.value {
+                Some(AnyValue {
+                    value: Some(any_value::Value::StringValue(value)),
+                }) => (kv.key.as_str(), value.as_str()),
+                _ => panic!("문자열 속성이 아닙니다: {:?}", kv),
+            })
+            .collect()
+    }
+
+    fn data_point_count(request: &ExportMetricsServiceRequest) -> usize {
+        request
+            .resource_metrics
+            .iter()
+            .flat_map(|resource| &resource.scope_metrics)
+            .flat_map(|scope| &scope.metrics)
+            .map(|metric| match &metric.data {
+                Some(metric::Data::Gauge(gauge)) => gauge.data_points.len(),
+                _ => 0,
+            })
+            .sum()
+    }
+
+    #[tokio::test]
+    async fn test_points_are_grouped_by_resource() {
+        let publisher = OtlpPublisher::new(config(
+            OtlpProtocol::HttpProtobuf,
+            "http://127.0.0.1:4318/v1/metrics".to_string(),
+        ))
+        .unwrap();
+
+        let request = publisher.inner.export_request(&[
+            point("db-1", "aurora-mysql", "ap-northeast-2", 1.0),
+            point("db-2", "mysql", "ap-northeast-2", 2.0),
+            point("db-3", "postgres", "ap-northeast-2", 3.0),
+        ]);
+
+        let resources: Vec<_> = request
+            .resource_metrics
+            .iter()
+            .map(|resource| attributes(&resource.resource.as_ref().unwrap().attributes))
+            .collect();
+        // 빈 값인 계정 ID는 리소스 속성에서 뺍니다
+        assert_eq!(
+            resources,
+            vec![
+                vec![
+                    ("cloud.provider", "aws"),
+                    ("cloud.region", "ap-northeast-2"),
+                    ("db.system", "mysql"),
+                ],
+                vec![
+                    ("cloud.provider", "aws"),
+                    ("cloud.region", "ap-northeast-2"),
+                    ("db.system", "postgresql"),
+                ],
+            ]
+        );
+
+        let mysql = &request.resource_metrics[0].scope_metrics[0];
+        assert_eq!(mysql.scope.as_ref().unwrap().name, "rds_exporter");
+        assert_eq!(mysql.metrics.len(), 1);
+        assert_eq!(mysql.metrics[0].name, "rds_cpuutilization");
+        let Some(metric::Data::Gauge(gauge)) = &mysql.metrics[0].data else {
+            panic!("gauge가 아닙니다");
+        };
+        assert_eq!(gauge.data_points.len(), 2);
+        let data_point = &gauge.data_points[0];
+        // engine은 db.system과 별개로 데이터 포인트 속성에 남습니다
+        assert_eq!(
+            attributes(&data_point.attributes),
+            vec![
+                ("db_instance_identifier", "db-1"),
+                ("engine", "aurora-mysql"),
+                ("stat", "Average"),
+            ]
+        );
+        assert_eq!(data_point.time_unix_nano, 1735689600000000000);
+        assert_eq!(
+            data_point.value,
+            Some(number_data_point::Value::AsDouble(1.0))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_exports_over_http_protobuf() {
+        // 빈 응답 본문은 기본 ExportMetricsServiceResponse로 읽힙니다
+        let (receiver, url) = HttpReceiver::start(&[503], 200);
+        let publisher = OtlpPublisher::new(config(
+            OtlpProtocol::HttpProtobuf,
+            format!("{}/v1/metrics", url),
+        ))
+        .unwrap();
+        publisher
+            .publish(vec![
+                point("db-1", "mysql", "ap-northeast-2", 1.0),
+                point("db-2", "mysql", "ap-northeast-2", 2.0),
+                point("db-3", "mysql", "ap-northeast-2", 3.0),
+            ])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+
+        // 첫 요청은 503으로 실패하여 같은 배치를 다시 보내고, 나머지는 다음 배치로 보냅니다
+        let requests = receiver.requests();
+        let sizes: Vec<_> = requests
+            .iter()
+            .map(|request| {
+                data_point_count(
+                    &ExportMetricsServiceRequest::decode(request.body.as_ref()).unwrap(),
+                )
+            })
+            .collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(requests[0].path, "/v1/metrics");
+        let headers = &requests[0].headers;
+        assert_eq!(headers["content-type"], "application/x-protobuf");
+        assert_eq!(headers["authorization"], "Bearer secret");
+        assert_eq!(headers["x-scope-orgid"], "dba");
+    }
+
+    /// 받은 요청을 기록하고 준비된 오류를 순서대로 돌려주는 테스트용 OTLP/gRPC collector
+    #[derive(Default)]
+    struct Collector {
+        errors: Mutex<VecDeque<tonic::Status>>,
+        requests: Arc<Mutex<Vec<(MetadataMap, ExportMetricsServiceRequest)>>>,
+    }
+
+    #[async_trait]
+    impl MetricsService for Collector {
+        async fn export(
+            &self,
+            request: tonic::Request<ExportMetricsServiceRequest>,
+        ) -> Result<tonic::Response<ExportMetricsServiceResponse>, tonic::Status> {
+            let metadata = request.metadata().clone();
+            self.requests.lock().push((metadata, request.into_inner()));
+            match self.errors.lock().pop_front() {
+                Some(status) => Err(status),
+                None => Ok(tonic::Response::new(ExportMetricsServiceResponse::default())),
+            }
+        }
+    }
+
+    async fn start_collector(
+        errors: Vec<tonic::Status>,
+    ) -> (
+        Arc<Mutex<Vec<(MetadataMap, ExportMetricsServiceRequest)>>>,
+        String,
+    ) {
+        let collector = Collector {
+            errors: Mutex::new(errors.into()),
+            ..Default::default()
+        };
+        let requests = Arc::clone(&collector.requests);
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let incoming = futures_util::stream::unfold(listener, |listener| async move {
+            let stream = listener.accept().await.map(|(stream, _)| stream);
+            Some((stream, listener))
+        });
+        tokio::spawn(
+            tonic::transport::Server::builder()
+                .add_service(MetricsServiceServer::new(collector))
+                .serve_with_incoming(incoming),
+        );
+        (requests, format!("http://{}", addr))
+    }
+
+    #[tokio::test]
+    async fn test_exports_over_grpc() {
+        let (requests, url) =
+            start_collector(vec![tonic::Status::unavailable("collector 시작 중")]).await;
+        let publisher = OtlpPublisher::new(config(OtlpProtocol::Grpc, url)).unwrap();
+
+        publisher
+            .publish(vec![point("db-1", "postgres", "ap-northeast-2", 1.0)])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+
+        // UNAVAILABLE은 재시도합니다
+        let received = requests.lock().clone();
+        assert_eq!(received.len(), 2);
+        let (metadata, request) = &received[1];
+        assert_eq!(metadata.get("authorization").unwrap(), "Bearer secret");
+        assert_eq!(metadata.get("x-scope-orgid").unwrap(), "dba");
+        assert_eq!(data_point_count(request), 1);
+
+        // 재시도할 수 없는 오류는 재시도하지 않고 배치를 버립니다
+        let (requests, url) =
+            start_collector(vec![tonic::Status::invalid_argument("잘못된 요청")]).await;
+        let publisher = OtlpPublisher::new(config(OtlpProtocol::Grpc, url)).unwrap();
+        publisher
+            .inner
+            .queue
+            .push([point("db-1", "postgres", "ap-northeast-2", 1.0)]);
+        publisher.flush().await.unwrap();
+        assert_eq!(requests.lock().len(), 1);
+        assert_eq!(publisher.inner.queue.len(), 0);
+
+        // 재시도 후에도 실패한 배치는 다음 주기를 위해 큐에 남깁니다
+        let (requests, url) =
+            start_collector(vec![tonic::Status::unavailable("collector 시작 중"); 3]).await;
+        let publisher = OtlpPublisher::new(config(OtlpProtocol::Grpc, url)).unwrap();
+        publisher
+            .inner
+            .queue
+            .push([point("db-1", "postgres", "ap-northeast-2", 1.0)]);
+        assert!(publisher.flush().await.is_err());
+        assert_eq!(requests.lock().len(), 3);
+        assert_eq!(publisher.inner.queue.len(), 1);
+    }
+
+    #[test]
+    fn test_config_from_settings() {
+        let publishers = PublishersSettings::example();
+        let config = OtlpConfig::from_settings(publishers.otlp.as_ref().unwrap(), StatStyle::Label);
+
+        assert_eq!(config.protocol, OtlpProtocol::HttpProtobuf);
+        assert_eq!(config.http.url, "http://otel-collector:4318/v1/metrics");
+        assert_eq!(
+            config.headers,
+            vec![("X-Scope-OrgID".to_string(), "dba".to_string())]
+        );
+        assert_eq!(
+            config.http.auth,
+            Some(HttpAuth::Bearer("secret".to_string()))
+        );
+    }
+}
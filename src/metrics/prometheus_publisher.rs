@@ -1,52 +1,211 @@
 // src/metrics/prometheus_publisher.rs
-use crate::metrics::collector::{MetricPoint, MetricPublisher};
+use crate::config::StatStyle;
+use crate::exporter_metrics::{LABEL_SCHEMA_CONFLICTS, REGISTRY, SERIES_REMOVED};
+use crate::metrics::collector::{
+    LabelSchema, MetricKind, MetricPoint, MetricPublisher, MetricScope,
+};
 use async_trait::async_trait;
 use lazy_static::lazy_static;
 use parking_lot::RwLock;
-use prometheus::{GaugeVec, Opts, Registry};
+use prometheus::{GaugeVec, Opts};
 use std::collections::HashMap;
+use std::sync::Arc;
 use tracing::{debug, warn};
 
+/// (메트릭 이름, 이름순으로 정렬된 레이블 쌍)
+type SeriesKey = (String, Vec<(String, String)>);
+
 lazy_static! {
-    static ref REGISTRY: Registry = Registry::new();
-    static ref METRICS: RwLock<HashMap<String, GaugeVec>> = RwLock::new(HashMap::new());
+    /// 메트릭 이름 -> (GaugeVec, 등록 시 사용한 레이블 이름)
+    static ref METRICS: RwLock<HashMap<String, (GaugeVec, Vec<String>)>> =
+        RwLock::new(HashMap::new());
+}
+
+#[derive(Debug, Clone, Copy)]
+struct SeriesState {
+    timestamp_ms: i64,
+    /// 마지막으로 값이 기록된 발행 주기
+    last_cycle: u64,
+}
+
+/// 발행 주기마다 어떤 시계열이 갱신되었는지 추적합니다.
+#[derive(Debug, Default)]
+struct SeriesTracker {
+    cycle: u64,
+    series: HashMap<SeriesKey, SeriesState>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct PrometheusConfig {
+    /// CloudWatch 데이터 포인트 시각을 exposition에 함께 노출할지 여부
+    pub export_timestamps: bool,
+    pub stat_style: StatStyle,
+    /// 이 주기 수만큼 연속으로 갱신되지 않은 시계열을 제거합니다 (0이면 제거하지 않음)
+    pub stale_after_cycles: u64,
+    pub label_schema: LabelSchema,
 }
 
 #[derive(Clone)]
-pub struct PrometheusPublisher {}
+pub struct PrometheusPublisher {
+    config: PrometheusConfig,
+    tracker: Arc<RwLock<SeriesTracker>>,
+}
 
 impl PrometheusPublisher {
-    pub fn new() -> Self {
-        Self {}
+    pub fn new(config: PrometheusConfig) -> Self {
+        Self {
+            config,
+            tracker: Arc::new(RwLock::new(SeriesTracker::default())),
+        }
+    }
+
+    fn series_key<'a>(
+        name: &str,
+        labels: impl IntoIterator<Item = (&'a str, &'a str)>,
+    ) -> SeriesKey {
+        let mut labels: Vec<(String, String)> = labels
+            .into_iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        labels.sort();
+        (name.to_string(), labels)
+    }
+
+    /// 기록해 둔 CloudWatch 시각을 수집된 메트릭 패밀리에 적용합니다.
+    fn apply_timestamps(&self, families: &mut [prometheus::proto::MetricFamily]) {
+        let tracker = self.tracker.read();
+        for family in families.iter_mut() {
+            let name = family.get_name().to_string();
+            for metric in family.mut_metric().iter_mut() {
+                let key = Self::series_key(
+                    &name,
+                    metric
+                        .get_label()
+                        .iter()
+                        .map(|label| (label.get_name(), label.get_value())),
+                );
+                if let Some(state) = tracker.series.get(&key) {
+                    metric.set_timestamp_ms(state.timestamp_ms);
+                }
+            }
+        }
+    }
+
+    /// `stale_after_cycles` 동안 갱신되지 않은 시계열을 GaugeVec에서 제거합니다.
+    fn remove_stale_series(&self, tracker: &mut SeriesTracker) {
+        if self.config.stale_after_cycles == 0 {
+            return;
+        }
+
+        let cycle = tracker.cycle;
+        let stale_after = self.config.stale_after_cycles;
+        let metrics = METRICS.read();
+
+        tracker.series.retain(|(name, labels), state| {
+            if cycle - state.last_cycle < stale_after {
+                return true;
+            }
+
+            if let Some((gauge, _)) = metrics.get(name) {
+                // 레이블 이름이 정렬되어 등록되므로 키의 순서가 곧 등록 순서입니다
+                let label_values: Vec<&str> = labels.iter().map(|(_, v)| v.as_str()).collect();
+                if let Err(e) = gauge.remove_label_values(&label_values) {
+                    debug!("시계열 제거 실패 ({} {:?}): {}", name, labels, e);
+                }
+            }
+            debug!(
+                "오래된 시계열 제거: {} {:?} (마지막 갱신 주기: {})",
+                name, labels, state.last_cycle
+            );
+            SERIES_REMOVED.inc();
+            false
+        });
+    }
+
+    /// 범위별 레이블 스키마에 `stat` 레이블(label 방식일 때)을 더해 이름순으로 정렬합니다.
+    /// 정보 메트릭은 수집기가 채운 레이블을 그대로 사용합니다.
+    fn label_names(&self, metric: &MetricPoint) -> Vec<String> {
+        let mut names = match metric.kind {
+            MetricKind::CloudWatch => {
+                let mut names = self.config.label_schema.labels(metric.scope).to_vec();
+                if self.config.stat_style == StatStyle::Label {
+                    names.push("stat".to_string());
+                }
+                names
+            }
+            MetricKind::Info => metric.additional_tags.keys().cloned().collect(),
+        };
+        names.sort();
+        names.dedup();
+        names
     }
 
     fn get_or_create_metric(
         &self,
         name: &str,
         help: &str,
-        label_names: &[&str],
+        label_names: &[String],
     ) -> anyhow::Result<GaugeVec> {
         let mut metrics = METRICS.write();
 
-        if let Some(gauge) = metrics.get(name) {
-            return Ok(gauge.clone());
+        if let Some((gauge, registered)) = metrics.get(name) {
+            if registered.as_slice() == label_names {
+                return Ok(gauge.clone());
+            }
+
+            LABEL_SCHEMA_CONFLICTS.with_label_values(&[name]).inc();
+            warn!(
+                "레이블 스키마 충돌 ({}): 등록됨 {:?}, 요청 {:?}",
+                name, registered, label_names
+            );
+            return Err(anyhow::anyhow!("레이블 스키마 충돌: {}", name));
         }
 
         let opts = Opts::new(name, help);
-        let gauge = GaugeVec::new(opts, label_names)?;
+        let label_refs: Vec<&str> = label_names.iter().map(String::as_str).collect();
+        let gauge = GaugeVec::new(opts, &label_refs)?;
 
         if let Err(e) = REGISTRY.register(Box::new(gauge.clone())) {
+            // 같은 이름의 다른 메트릭(자체 메트릭 등)과 충돌한 경우
+            LABEL_SCHEMA_CONFLICTS.with_label_values(&[name]).inc();
             warn!("메트릭 등록 실패 ({}): {}", name, e);
             return Err(anyhow::anyhow!("메트릭 등록 실패: {}", e));
         }
 
-        metrics.insert(name.to_string(), gauge.clone());
+        metrics.insert(name.to_string(), (gauge.clone(), label_names.to_vec()));
         Ok(gauge)
     }
+}
 
-    fn create_metric_name(&self, metric: &MetricPoint) -> String {
-        format!("rds_{}", metric.metric_name.to_lowercase())
+/// Prometheus 메트릭 이름. 푸시 방식 발행자도 같은 이름을 사용합니다.
+pub(crate) fn metric_name(metric: &MetricPoint, stat_style: StatStyle) -> String {
+    if metric.kind == MetricKind::Info {
+        return format!("rds_{}", metric.metric_name.to_lowercase());
     }
+
+    let base = match metric.scope {
+        MetricScope::Instance => format!("rds_{}", metric.metric_name.to_lowercase()),
+        MetricScope::Cluster => format!("rds_cluster_{}", metric.metric_name.to_lowercase()),
+    };
+
+    match stat_style {
+        StatStyle::Label => base,
+        StatStyle::Suffix => format!("{}_{}", base, sanitize_stat(&metric.stat)),
+    }
+}
+
+/// `p99.9` -> `p99_9`, `tm(10%:90%)` -> `tm_10_90`
+fn sanitize_stat(stat: &str) -> String {
+    let sanitized: String = stat
+        .to_lowercase()
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() { c } else { '_' })
+        .collect();
+    sanitized
+        .split('_')
+        .filter(|part| !part.is_empty())
+        .collect::<Vec<_>>()
+        .join("_")
 }
 
 #[async_trait]
@@ -54,12 +213,15 @@ impl MetricPublisher for PrometheusPublisher {
     async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
         debug!("Prometheus 메트릭 발행 시작: {} 개", metrics.len());
 
+        let mut tracker = self.tracker.write();
+        tracker.cycle += 1;
+        let cycle = tracker.cycle;
+
         for metric in metrics {
-            let metric_name = self.create_metric_name(&metric);
+            let metric_name = metric_name(&metric, self.config.stat_style);
             let help = format!("RDS metric: {}", metric_name);
 
-            let label_names: Vec<&str> =
-                metric.additional_tags.keys().map(|s| s.as_str()).collect();
+            let label_names = self.label_names(&metric);
 
             debug!(
                 "메트릭 처리: {} (값: {}, 레이블: {:?})",
@@ -68,19 +230,39 @@ impl MetricPublisher for PrometheusPublisher {
 
             match self.get_or_create_metric(&metric_name, &help, &label_names) {
                 Ok(gauge) => {
+                    // 스키마에 없는 레이블은 빈 문자열로 채웁니다
                     let label_values: Vec<&str> = label_names
                         .iter()
-                        .map(|&name| {
-                            metric
+                        .map(|name| match name.as_str() {
+                            "stat"
+                                if metric.kind == MetricKind::CloudWatch
+                                    && self.config.stat_style == StatStyle::Label =>
+                            {
+                                metric.stat.as_str()
+                            }
+                            name => metric
                                 .additional_tags
                                 .get(name)
                                 .map(|s| s.as_str())
-                                .unwrap_or("")
+                                .unwrap_or(""),
                         })
                         .collect();
 
                     let metric_gauge = gauge.with_label_values(&label_values);
                     metric_gauge.set(metric.value);
+                    tracker.series.insert(
+                        Self::series_key(
+                            &metric_name,
+                            label_names
+                                .iter()
+                                .map(String::as_str)
+                                .zip(label_values.iter().copied()),
+                        ),
+                        SeriesState {
+                            timestamp_ms: metric.timestamp.timestamp_millis(),
+                            last_cycle: cycle,
+                        },
+                    );
                     debug!(
                         "메트릭 설정 완료: {}{{{}}} = {}",
                         metric_name,
@@ -100,11 +282,241 @@ impl MetricPublisher for PrometheusPublisher {
             }
         }
 
+        self.remove_stale_series(&mut tracker);
+
         debug!("Prometheus 메트릭 발행 완료");
         Ok(())
     }
 
     fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
-        REGISTRY.gather()
+        let mut families = REGISTRY.gather();
+        if self.config.export_timestamps {
+            self.apply_timestamps(&mut families);
+        }
+        families
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::{TimeZone, Utc};
+    use prometheus::{Encoder, TextEncoder};
+
+    fn encode(publisher: &PrometheusPublisher, name: &str) -> String {
+        let families: Vec<_> = publisher
+            .gather()
+            .into_iter()
+            .filter(|family| family.get_name() == name)
+            .collect();
+        let mut buffer = Vec::new();
+        TextEncoder::new().encode(&families, &mut buffer).unwrap();
+        String::from_utf8(buffer).unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_exports_cloudwatch_timestamp_when_enabled() {
+        let timestamp = Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap();
+        let metric = MetricPoint {
+            value: 42.0,
+            timestamp,
+            metric_name: "TimestampTest".to_string(),
+            stat: "Average".to_string(),
+            scope: MetricScope::Instance,
+            kind: MetricKind::CloudWatch,
+            additional_tags: HashMap::from([(
+                "db_instance_identifier".to_string(),
+                "db-1".to_string(),
+            )]),
+        };
+
+        let plain = PrometheusPublisher::new(PrometheusConfig {
+            stat_style: StatStyle::Suffix,
+            ..Default::default()
+        });
+        plain.publish(vec![metric.clone()]).await.unwrap();
+        assert!(encode(&plain, "rds_timestamptest_average")
+            .contains("rds_timestamptest_average{account_id=\"\",availability_zone=\"\",class=\"\",db_instance_identifier=\"db-1\",engine=\"\",engine_version=\"\",region=\"\"} 42\n"));
+
+        let with_timestamps = PrometheusPublisher::new(PrometheusConfig {
+            export_timestamps: true,
+            stat_style: StatStyle::Suffix,
+            ..Default::default()
+        });
+        with_timestamps.publish(vec![metric]).await.unwrap();
+        assert!(
+            encode(&with_timestamps, "rds_timestamptest_average").contains(&format!(
+                "rds_timestamptest_average{{account_id=\"\",availability_zone=\"\",class=\"\",db_instance_identifier=\"db-1\",engine=\"\",engine_version=\"\",region=\"\"}} 42 {}",
+                timestamp.timestamp_millis()
+            ))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_stat_style() {
+        let point = |stat: &str, value: f64| MetricPoint {
+            value,
+            timestamp: Utc::now(),
+            metric_name: "StatStyleTest".to_string(),
+            stat: stat.to_string(),
+            scope: MetricScope::Cluster,
+            kind: MetricKind::CloudWatch,
+            additional_tags: HashMap::new(),
+        };
+
+        let label = PrometheusPublisher::new(PrometheusConfig::default());
+        label
+            .publish(vec![point("Average", 1.0), point("p99", 2.0)])
+            .await
+            .unwrap();
+        let output = encode(&label, "rds_cluster_statstyletest");
+        assert!(output.contains(
+            "rds_cluster_statstyletest{account_id=\"\",db_cluster_identifier=\"\",engine=\"\",engine_version=\"\",readers=\"\",region=\"\",stat=\"Average\",writer=\"\"} 1\n"
+        ));
+        assert!(output.contains("stat=\"p99\",writer=\"\"} 2\n"));
+
+        let suffix = PrometheusPublisher::new(PrometheusConfig {
+            stat_style: StatStyle::Suffix,
+            ..Default::default()
+        });
+        suffix
+            .publish(vec![point("p99.9", 3.0), point("tm(10%:90%)", 4.0)])
+            .await
+            .unwrap();
+        assert!(encode(&suffix, "rds_cluster_statstyletest_p99_9")
+            .contains("readers=\"\",region=\"\",writer=\"\"} 3\n"));
+        assert!(encode(&suffix, "rds_cluster_statstyletest_tm_10_90")
+            .contains("readers=\"\",region=\"\",writer=\"\"} 4\n"));
+    }
+
+    #[tokio::test]
+    async fn test_stale_series_are_removed() {
+        let point = |instance: &str| MetricPoint {
+            value: 1.0,
+            timestamp: Utc::now(),
+            metric_name: "StaleTest".to_string(),
+            stat: "Average".to_string(),
+            scope: MetricScope::Instance,
+            kind: MetricKind::CloudWatch,
+            additional_tags: HashMap::from([(
+                "db_instance_identifier".to_string(),
+                instance.to_string(),
+            )]),
+        };
+        let publisher = PrometheusPublisher::new(PrometheusConfig {
+            stale_after_cycles: 2,
+            ..Default::default()
+        });
+        let removed_before = SERIES_REMOVED.get();
+
+        publisher
+            .publish(vec![point("db-kept"), point("db-deleted")])
+            .await
+            .unwrap();
+        // 한 주기 누락은 아직 유지됩니다
+        publisher.publish(vec![point("db-kept")]).await.unwrap();
+        assert!(encode(&publisher, "rds_staletest").contains("db-deleted"));
+
+        publisher.publish(vec![point("db-kept")]).await.unwrap();
+        let output = encode(&publisher, "rds_staletest");
+        assert!(output.contains("db-kept"));
+        assert!(!output.contains("db-deleted"));
+        assert!(SERIES_REMOVED.get() > removed_before);
+    }
+
+    #[tokio::test]
+    async fn test_missing_labels_are_filled_with_empty_strings() {
+        let point = |tags: &[(&str, &str)]| MetricPoint {
+            value: 1.0,
+            timestamp: Utc::now(),
+            metric_name: "SchemaTest".to_string(),
+            stat: "Average".to_string(),
+            scope: MetricScope::Instance,
+            kind: MetricKind::CloudWatch,
+            additional_tags: tags
+                .iter()
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+                .collect(),
+        };
+        let publisher = PrometheusPublisher::new(PrometheusConfig::default());
+
+        publisher
+            .publish(vec![
+                point(&[
+                    ("db_instance_identifier", "db-1"),
+                    ("availability_zone", "ap-northeast-2a"),
+                    ("engine", "mysql"),
+                ]),
+                // Aurora Serverless처럼 AZ가 없는 인스턴스
+                point(&[
+                    ("engine", "aurora-mysql"),
+                    ("db_instance_identifier", "db-2"),
+                ]),
+            ])
+            .await
+            .unwrap();
+
+        let output = encode(&publisher, "rds_schematest");
+        assert!(output.contains(
+            "rds_schematest{account_id=\"\",availability_zone=\"ap-northeast-2a\",class=\"\",db_instance_identifier=\"db-1\",engine=\"mysql\",engine_version=\"\",region=\"\",stat=\"Average\"} 1"
+        ));
+        assert!(output.contains(
+            "rds_schematest{account_id=\"\",availability_zone=\"\",class=\"\",db_instance_identifier=\"db-2\",engine=\"aurora-mysql\",engine_version=\"\",region=\"\",stat=\"Average\"} 1"
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_conflicting_schema_is_reported() {
+        let point = MetricPoint {
+            value: 1.0,
+            timestamp: Utc::now(),
+            metric_name: "ConflictTest".to_string(),
+            stat: "Average".to_string(),
+            scope: MetricScope::Instance,
+            kind: MetricKind::CloudWatch,
+            additional_tags: HashMap::new(),
+        };
+        let first = PrometheusPublisher::new(PrometheusConfig::default());
+        first.publish(vec![point.clone()]).await.unwrap();
+
+        let second = PrometheusPublisher::new(PrometheusConfig {
+            label_schema: LabelSchema {
+                instance: vec!["region".to_string()],
+                cluster: Vec::new(),
+            },
+            ..Default::default()
+        });
+        second.publish(vec![point]).await.unwrap();
+
+        assert_eq!(
+            LABEL_SCHEMA_CONFLICTS
+                .with_label_values(&["rds_conflicttest"])
+                .get(),
+            1
+        );
+    }
+
+    #[tokio::test]
+    async fn test_info_metric_uses_its_own_labels() {
+        let publisher = PrometheusPublisher::new(PrometheusConfig::default());
+        publisher
+            .publish(vec![MetricPoint {
+                value: 1.0,
+                timestamp: Utc::now(),
+                metric_name: "infotest_tags".to_string(),
+                stat: String::new(),
+                scope: MetricScope::Instance,
+                kind: MetricKind::Info,
+                additional_tags: HashMap::from([
+                    ("db_instance_identifier".to_string(), "db-1".to_string()),
+                    ("tag_team".to_string(), "payments".to_string()),
+                ]),
+            }])
+            .await
+            .unwrap();
+
+        assert!(encode(&publisher, "rds_infotest_tags").contains(
+            "rds_infotest_tags{db_instance_identifier=\"db-1\",tag_team=\"payments\"} 1\n"
+        ));
     }
 }
@@ -0,0 +1,362 @@
+// src/metrics/pushgateway_publisher.rs
+use crate::config::{PushMethod, PushgatewaySettings};
+use crate::metrics::collector::{MetricPoint, MetricPublisher};
+use crate::metrics::http_sender::{HttpSender, HttpSenderConfig, SendError};
+use crate::metrics::prometheus_publisher::PrometheusPublisher;
+use async_trait::async_trait;
+use base64::Engine;
+use parking_lot::Mutex;
+use prometheus::proto::{Metric, MetricFamily};
+use prometheus::{Encoder, TextEncoder};
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_TYPE};
+use reqwest::Method;
+use std::collections::{BTreeMap, BTreeSet};
+use tracing::{debug, info, warn};
+
+const PUBLISHER: &str = "pushgateway";
+
+/// 그룹 레이블 (이름, 값). `job` 다음에 이 순서로 그룹 경로를 만듭니다.
+type GroupKey = Vec<(String, String)>;
+
+impl PushMethod {
+    fn method(&self) -> Method {
+        match self {
+            PushMethod::Put => Method::PUT,
+            PushMethod::Post => Method::POST,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PushgatewayConfig {
+    /// `url`은 Pushgateway 주소 (예: `http://pushgateway:9091`)
+    pub http: HttpSenderConfig,
+    pub job: String,
+    pub method: PushMethod,
+    /// 모든 그룹에 붙는 고정 그룹 레이블
+    pub grouping_key: Vec<(String, String)>,
+    /// 값별로 그룹을 나눌 메트릭 레이블 (예: `region`, `account_id`)
+    pub group_by: Vec<String>,
+    /// 정상 종료할 때 푸시한 그룹을 삭제할지 여부
+    pub delete_on_shutdown: bool,
+}
+
+impl PushgatewayConfig {
+    pub fn from_settings(settings: &PushgatewaySettings) -> Self {
+        Self {
+            http: HttpSenderConfig::from_settings(&settings.http, &settings.url),
+            job: settings.job.clone(),
+            method: settings.method,
+            grouping_key: settings
+                .grouping_key
+                .iter()
+                .map(|(name, value)| (name.clone(), value.clone()))
+                .collect(),
+            group_by: settings.group_by.clone(),
+            delete_on_shutdown: settings.delete_on_shutdown,
+        }
+    }
+}
+
+/// 수집 주기마다 `/metrics`와 같은 레지스트리 내용을 Pushgateway에 푸시하는 발행자
+///
+/// 레지스트리는 함께 등록된 [`PrometheusPublisher`]가 갱신하므로, 발행자 목록에서 그 뒤에 두어야
+/// 같은 주기의 값을 푸시합니다. Pushgateway는 타임스탬프가 붙은 메트릭을 거부하므로
+/// `export_timestamps` 설정과 관계없이 타임스탬프를 빼고 보냅니다.
+pub struct PushgatewayPublisher {
+    sender: HttpSender,
+    config: PushgatewayConfig,
+    registry: PrometheusPublisher,
+    /// 마지막 주기에 푸시한 그룹. 사라진 그룹은 다음 주기에, 남은 그룹은 종료할 때 삭제합니다.
+    pushed: Mutex<BTreeSet<GroupKey>>,
+}
+
+impl PushgatewayPublisher {
+    pub fn new(
+        config: PushgatewayConfig,
+        registry: PrometheusPublisher,
+    ) -> Result<Self, SendError> {
+        Ok(Self {
+            sender: HttpSender::new(PUBLISHER, config.http.clone())?,
+            config,
+            registry,
+            pushed: Mutex::new(BTreeSet::new()),
+        })
+    }
+
+    /// 메트릭 패밀리를 그룹별로 나눕니다. 메트릭에 없는 `group_by` 레이블은 빈 값으로 봅니다.
+    fn groups(&self, families: Vec<MetricFamily>) -> BTreeMap<GroupKey, Vec<MetricFamily>> {
+        let mut groups: BTreeMap<GroupKey, Vec<MetricFamily>> = BTreeMap::new();
+
+        for mut family in families {
+            let mut by_group: BTreeMap<GroupKey, Vec<Metric>> = BTreeMap::new();
+            for mut metric in family.take_metric().into_iter() {
+                metric.clear_timestamp_ms();
+                let mut key = self.config.grouping_key.clone();
+                for name in &self.config.group_by {
+                    let value = metric
+                        .get_label()
+                        .iter()
+                        .find(|label| label.get_name() == name)
+                        .map(|label| label.get_value().to_string())
+                        .unwrap_or_default();
+                    key.push((name.clone(), value));
+                }
+                by_group.entry(key).or_default().push(metric);
+            }
+
+            for (key, metrics) in by_group {
+                let mut group_family = family.clone();
+                group_family.set_metric(metrics.into());
+                groups.entry(key).or_default().push(group_family);
+            }
+        }
+
+        groups
+    }
+
+    /// `/metrics/job/<job>/<레이블>/<값>...` 형식의 그룹 주소
+    fn group_url(&self, key: &GroupKey) -> String {
+        let mut url = format!(
+            "{}/metrics{}",
+            self.config.http.url.trim_end_matches('/'),
+            path_segment("job", &self.config.job)
+        );
+        for (name, value) in key {
+            url.push_str(&path_segment(name, value));
+        }
+        url
+    }
+
+    async fn delete_groups(
+        &self,
+        groups: impl IntoIterator<Item = GroupKey>,
+    ) -> Result<(), SendError> {
+        let mut last_error = None;
+        for key in groups {
+            let url = self.group_url(&key);
+            match self
+                .sender
+                .send_to(&url, Method::DELETE, HeaderMap::new(), Vec::new())
+                .await
+            {
+                Ok(_) => debug!("Pushgateway 그룹 삭제: {}", url),
+                Err(e) => {
+                    warn!("Pushgateway 그룹 삭제 실패 ({}): {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+        last_error.map_or(Ok(()), Err)
+    }
+}
+
+#[async_trait]
+impl MetricPublisher for PushgatewayPublisher {
+    async fn publish(&self, _metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+        let groups = self.groups(self.registry.gather());
+        let encoder = TextEncoder::new();
+        let mut headers = HeaderMap::new();
+        headers.insert(CONTENT_TYPE, HeaderValue::from_str(encoder.format_type())?);
+
+        let mut pushed = BTreeSet::new();
+        let mut last_error = None;
+        for (key, families) in groups {
+            let mut body = Vec::new();
+            encoder.encode(&families, &mut body)?;
+
+            let url = self.group_url(&key);
+            match self
+                .sender
+                .send_to(&url, self.config.method.method(), headers.clone(), body)
+                .await
+            {
+                Ok(_) => debug!("Pushgateway 푸시 완료: {}", url),
+                Err(e) => {
+                    warn!("Pushgateway 푸시 실패 ({}): {}", url, e);
+                    last_error = Some(e);
+                }
+            }
+            // 실패한 그룹도 이전 값이 남아 있을 수 있으므로 삭제 대상에 둡니다
+            pushed.insert(key);
+        }
+
+        // 대상이 사라져 더 이상 푸시하지 않는 그룹은 오래된 값이 남지 않도록 삭제합니다
+        let stale: Vec<GroupKey> = {
+            let mut previous = self.pushed.lock();
+            let stale = previous.difference(&pushed).cloned().collect();
+            *previous = pushed;
+            stale
+        };
+        if !stale.is_empty() {
+            info!(
+                "푸시하지 않는 Pushgateway 그룹 {} 개를 삭제합니다",
+                stale.len()
+            );
+            if let Err(e) = self.delete_groups(stale).await {
+                last_error.get_or_insert(e);
+            }
+        }
+
+        match last_error {
+            Some(e) => Err(anyhow::anyhow!("Pushgateway 푸시 실패: {}", e)),
+            None => Ok(()),
+        }
+    }
+
+    fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+        Vec::new()
+    }
+
+    /// 종료할 때 `delete_on_shutdown`이면 푸시한 그룹을 삭제합니다.
+    async fn flush(&self) -> anyhow::Result<()> {
+        if !self.config.delete_on_shutdown {
+            return Ok(());
+        }
+
+        let groups = std::mem::take(&mut *self.pushed.lock());
+        info!("Pushgateway 그룹 {} 개를 삭제합니다", groups.len());
+        self.delete_groups(groups).await?;
+        Ok(())
+    }
+}
+
+/// 그룹 경로의 `/<이름>/<값>` 부분. 값이 비었거나 URL 경로에 그대로 쓸 수 없는 문자가 있으면
+/// Pushgateway의 `<이름>@base64/<값>` 형식으로 인코딩합니다.
+fn path_segment(name: &str, value: &str) -> String {
+    let plain = !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.' | '~'));
+    if plain {
+        format!("/{}/{}", name, value)
+    } else if value.is_empty() {
+        format!("/{}@base64/=", name)
+    } else {
+        format!(
+            "/{}@base64/{}",
+            name,
+            base64::engine::general_purpose::URL_SAFE.encode(value)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PublishersSettings;
+    use crate::exporter_metrics::DATAPOINTS_PUBLISHED;
+    use crate::metrics::prometheus_publisher::PrometheusConfig;
+    use crate::metrics::test_util::{self, http_config, HttpReceiver};
+
+    #[test]
+    fn test_path_segment_encoding() {
+        assert_eq!(
+            path_segment("region", "ap-northeast-2"),
+            "/region/ap-northeast-2"
+        );
+        assert_eq!(path_segment("account_id", ""), "/account_id@base64/=");
+        assert_eq!(path_segment("path", "a/b"), "/path@base64/YS9i");
+    }
+
+    fn info_point(group: &str) -> MetricPoint {
+        test_util::info_point(
+            "pushgateway_test_info",
+            &[("pushgateway_test_group", group)],
+        )
+    }
+
+    #[tokio::test]
+    async fn test_pushes_groups_and_deletes_them() {
+        let (receiver, url) = HttpReceiver::start(&[], 200);
+
+        // 레지스트리는 전역이므로 자체 메트릭과 다른 테스트의 메트릭은
+        // pushgateway_test_group이 빈 그룹에 들어갑니다
+        let _ = DATAPOINTS_PUBLISHED.get();
+        let registry = PrometheusPublisher::new(PrometheusConfig {
+            export_timestamps: true,
+            stale_after_cycles: 1,
+            ..Default::default()
+        });
+        let publisher = PushgatewayPublisher::new(
+            PushgatewayConfig {
+                http: http_config(format!("{}/", url)),
+                job: "rds_exporter".to_string(),
+                method: PushMethod::Put,
+                grouping_key: vec![("instance".to_string(), "prod".to_string())],
+                group_by: vec!["pushgateway_test_group".to_string()],
+                delete_on_shutdown: true,
+            },
+            registry.clone(),
+        )
+        .unwrap();
+        let base = "/metrics/job/rds_exporter/instance/prod/pushgateway_test_group";
+        let requests_for = |method: &str| -> Vec<(String, String)> {
+            let mut found: Vec<_> = receiver
+                .requests()
+                .iter()
+                .filter(|request| request.method == method)
+                .map(|request| (request.path.clone(), request.text()))
+                .collect();
+            found.sort();
+            found
+        };
+
+        let points = vec![info_point("a"), info_point("b")];
+        registry.publish(points.clone()).await.unwrap();
+        publisher.publish(points).await.unwrap();
+
+        let puts = requests_for("PUT");
+        let paths: Vec<_> = puts.iter().map(|(path, _)| path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec![
+                format!("{}/a", base),
+                format!("{}/b", base),
+                format!("{}@base64/=", base),
+            ]
+        );
+        assert!(puts[0]
+            .1
+            .contains("rds_pushgateway_test_info{pushgateway_test_group=\"a\"} 1\n"));
+        assert!(!puts[0].1.contains("pushgateway_test_group=\"b\""));
+
+        // b 그룹이 사라지면 삭제합니다
+        receiver.clear();
+        let points = vec![info_point("a")];
+        registry.publish(points.clone()).await.unwrap();
+        publisher.publish(points).await.unwrap();
+        let deletes: Vec<_> = requests_for("DELETE")
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(deletes, vec![format!("{}/b", base)]);
+
+        // 종료할 때 남은 그룹을 삭제합니다
+        receiver.clear();
+        publisher.flush().await.unwrap();
+        let deletes: Vec<_> = requests_for("DELETE")
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            deletes,
+            vec![format!("{}/a", base), format!("{}@base64/=", base)]
+        );
+    }
+
+    #[test]
+    fn test_config_from_settings() {
+        let publishers = PublishersSettings::example();
+        let config = PushgatewayConfig::from_settings(publishers.pushgateway.as_ref().unwrap());
+
+        assert_eq!(config.http.url, "http://pushgateway:9091");
+        assert_eq!(config.job, "rds_exporter");
+        assert_eq!(config.method, PushMethod::Post);
+        assert_eq!(
+            config.grouping_key,
+            vec![("instance".to_string(), "prod".to_string())]
+        );
+        assert_eq!(config.group_by, vec!["region", "account_id"]);
+    }
+}
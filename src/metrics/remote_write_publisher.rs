@@ -0,0 +1,437 @@
+// src/metrics/remote_write_publisher.rs
+use crate::config::{RemoteWriteSettings, StatStyle};
+use crate::exporter_metrics::PUBLISHER_DROPPED_SAMPLES;
+use crate::metrics::collector::{MetricKind, MetricPoint, MetricPublisher};
+use crate::metrics::http_sender::{HttpSender, HttpSenderConfig, SendError};
+use crate::metrics::prometheus_publisher::metric_name;
+use crate::metrics::send_queue::SendQueue;
+use async_trait::async_trait;
+use parking_lot::Mutex;
+use prost::Message;
+use reqwest::header::{HeaderMap, HeaderValue, CONTENT_ENCODING, CONTENT_TYPE};
+use reqwest::Method;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{debug, error, warn};
+
+const PUBLISHER: &str = "remote_write";
+
+/// Prometheus remote write 1.0 프로토콜 메시지 (`prometheus/prompb/remote.proto`)
+#[derive(Clone, PartialEq, Message)]
+pub struct WriteRequest {
+    #[prost(message, repeated, tag = "1")]
+    pub timeseries: Vec<TimeSeries>,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct TimeSeries {
+    #[prost(message, repeated, tag = "1")]
+    pub labels: Vec<Label>,
+    #[prost(message, repeated, tag = "2")]
+    pub samples: Vec<Sample>,
+}
+
+#[derive(Clone, PartialEq, Eq, Hash, Message)]
+pub struct Label {
+    #[prost(string, tag = "1")]
+    pub name: String,
+    #[prost(string, tag = "2")]
+    pub value: String,
+}
+
+#[derive(Clone, PartialEq, Message)]
+pub struct Sample {
+    #[prost(double, tag = "1")]
+    pub value: f64,
+    /// Unix 밀리초
+    #[prost(int64, tag = "2")]
+    pub timestamp: i64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RemoteWriteConfig {
+    pub http: HttpSenderConfig,
+    /// 요청 하나에 담을 최대 시계열 수
+    pub batch_size: usize,
+    /// 전송을 기다리는 샘플의 최대 수. 넘치면 가장 오래된 샘플부터 버립니다.
+    pub queue_capacity: usize,
+    pub stat_style: StatStyle,
+    /// 이 주기 수 동안 발행되지 않은 시계열의 마지막 전송 시각을 잊습니다 (`exporter.stale_series_cycles`, 0이면 잊지 않음)
+    pub stale_after_cycles: u64,
+}
+
+impl RemoteWriteConfig {
+    pub fn from_settings(
+        settings: &RemoteWriteSettings,
+        stat_style: StatStyle,
+        stale_after_cycles: u64,
+    ) -> Self {
+        Self {
+            http: HttpSenderConfig::from_settings(&settings.http, &settings.url),
+            batch_size: settings.batch_size,
+            queue_capacity: settings.queue_capacity,
+            stat_style,
+            stale_after_cycles,
+        }
+    }
+}
+
+/// 시계열별 마지막으로 큐에 넣은 샘플 시각. 같은 CloudWatch 데이터 포인트를 다시 보내지 않습니다.
+#[derive(Default)]
+struct SentSeries {
+    /// 레이블 -> (마지막 샘플 시각, 마지막으로 발행된 주기)
+    last: HashMap<Vec<Label>, (i64, u64)>,
+    /// `publish` 호출 수
+    cycle: u64,
+}
+
+struct Inner {
+    sender: HttpSender,
+    config: RemoteWriteConfig,
+    queue: SendQueue<TimeSeries>,
+    sent: Mutex<SentSeries>,
+}
+
+/// `MetricPoint`를 Prometheus remote write 엔드포인트로 보내는 발행자
+///
+/// 시계열마다 마지막으로 보낸 타임스탬프를 기억해 같은 데이터 포인트를 다시 보내지 않으며,
+/// 샘플은 [`SendQueue`]를 거쳐 전송됩니다.
+#[derive(Clone)]
+pub struct RemoteWritePublisher {
+    inner: Arc<Inner>,
+}
+
+impl RemoteWritePublisher {
+    pub fn new(config: RemoteWriteConfig) -> Result<Self, SendError> {
+        Ok(Self {
+            inner: Arc::new(Inner {
+                sender: HttpSender::new(PUBLISHER, config.http.clone())?,
+                queue: SendQueue::new(PUBLISHER, config.queue_capacity),
+                config,
+                sent: Mutex::new(SentSeries::default()),
+            }),
+        })
+    }
+}
+
+impl Inner {
+    /// 이름순으로 정렬된 레이블. 값이 빈 레이블은 Prometheus에서 없는 레이블과 같으므로 뺍니다.
+    fn labels(&self, metric: &MetricPoint) -> Vec<Label> {
+        let mut labels: Vec<Label> = metric
+            .additional_tags
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| Label {
+                name: name.clone(),
+                value: value.clone(),
+            })
+            .collect();
+        if metric.kind == MetricKind::CloudWatch
+            && self.config.stat_style == StatStyle::Label
+            && !metric.stat.is_empty()
+        {
+            labels.push(Label {
+                name: "stat".to_string(),
+                value: metric.stat.clone(),
+            });
+        }
+        labels.push(Label {
+            name: "__name__".to_string(),
+            value: metric_name(metric, self.config.stat_style),
+        });
+        labels.sort_by(|a, b| a.name.cmp(&b.name));
+        labels
+    }
+
+    fn enqueue(&self, metrics: &[MetricPoint]) {
+        let mut sent = self.sent.lock();
+        let mut series = Vec::new();
+        sent.cycle += 1;
+        let cycle = sent.cycle;
+
+        for metric in metrics {
+            let labels = self.labels(metric);
+            let timestamp = metric.timestamp.timestamp_millis();
+            match sent.last.get_mut(&labels) {
+                Some((last, seen)) if *last >= timestamp => {
+                    *seen = cycle;
+                    continue;
+                }
+                _ => {}
+            }
+            sent.last.insert(labels.clone(), (timestamp, cycle));
+
+            series.push(TimeSeries {
+                labels,
+                samples: vec![Sample {
+                    value: metric.value,
+                    timestamp,
+                }],
+            });
+        }
+
+        // 사라진 인스턴스의 시계열이 쌓이지 않도록 오래 발행되지 않은 항목을 지웁니다 (0이면 지우지 않음)
+        let stale_after_cycles = self.config.stale_after_cycles;
+        if stale_after_cycles > 0 {
+            sent.last
+                .retain(|_, (_, seen)| cycle - *seen <= stale_after_cycles);
+        }
+
+        self.queue.push(series);
+    }
+
+    /// 큐가 빌 때까지 배치를 전송합니다. 재시도 후에도 실패하면 배치를 큐 앞에 되돌리고 멈춥니다.
+    async fn drain(&self) -> Result<(), SendError> {
+        self.queue
+            .drain(self.config.batch_size, |batch| self.send(batch))
+            .await
+    }
+
+    async fn send(&self, batch: Vec<TimeSeries>) -> Result<(), SendError> {
+        let count = batch.len();
+        let body = WriteRequest { timeseries: batch }.encode_to_vec();
+        let body = snap::raw::Encoder::new()
+            .compress_vec(&body)
+            .expect("snappy 압축은 메모리 버퍼에서 실패하지 않습니다");
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            CONTENT_TYPE,
+            HeaderValue::from_static("application/x-protobuf"),
+        );
+        headers.insert(CONTENT_ENCODING, HeaderValue::from_static("snappy"));
+        headers.insert(
+            "X-Prometheus-Remote-Write-Version",
+            HeaderValue::from_static("0.1.0"),
+        );
+
+        match self.sender.send(Method::POST, headers, body).await {
+            Ok(_) => debug!("remote write 전송 완료: {} 개", count),
+            Err(SendError::Rejected { status, body }) => {
+                // 다시 보내도 거부되므로 배치를 버립니다
+                PUBLISHER_DROPPED_SAMPLES
+                    .with_label_values(&[PUBLISHER])
+                    .inc_by(count as u64);
+                error!(
+                    "remote write 요청 거부 ({}), 샘플 {} 개를 버립니다: {}",
+                    status, count, body
+                );
+            }
+            Err(e) => return Err(e),
+        }
+        Ok(())
+    }
+}
+
+#[async_trait]
+impl MetricPublisher for RemoteWritePublisher {
+    async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+        self.inner.enqueue(&metrics);
+
+        let inner = Arc::clone(&self.inner);
+        tokio::spawn(async move {
+            if let Err(e) = inner.drain().await {
+                warn!("remote write 전송 실패, 다음 주기에 다시 시도합니다: {}", e);
+            }
+        });
+
+        Ok(())
+    }
+
+    fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+        Vec::new()
+    }
+
+    async fn flush(&self) -> anyhow::Result<()> {
+        self.inner.drain().await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::PublishersSettings;
+    use crate::metrics::http_sender::HttpAuth;
+    use crate::metrics::test_util::{http_config, instance_point, CapturedRequest, HttpReceiver};
+    use chrono::{TimeZone, Utc};
+    use std::time::Duration;
+
+    fn write_request(request: &CapturedRequest) -> WriteRequest {
+        let decoded = snap::raw::Decoder::new()
+            .decompress_vec(&request.body)
+            .unwrap();
+        WriteRequest::decode(decoded.as_slice()).unwrap()
+    }
+
+    fn publisher(url: String, batch_size: usize, queue_capacity: usize) -> RemoteWritePublisher {
+        RemoteWritePublisher::new(RemoteWriteConfig {
+            http: HttpSenderConfig {
+                auth: Some(HttpAuth::Bearer("secret".to_string())),
+                ..http_config(format!("{}/api/v1/write", url))
+            },
+            batch_size,
+            queue_capacity,
+            stat_style: StatStyle::Label,
+            stale_after_cycles: 2,
+        })
+        .unwrap()
+    }
+
+    fn point(instance: &str, minute: u32, value: f64) -> MetricPoint {
+        MetricPoint {
+            timestamp: Utc.with_ymd_and_hms(2025, 1, 1, 0, minute, 0).unwrap(),
+            ..instance_point(instance, value)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_sends_batches_with_cloudwatch_timestamps() {
+        let (receiver, url) = HttpReceiver::start(&[503], 204);
+        let publisher = publisher(url, 2, 100);
+
+        publisher
+            .publish(vec![
+                point("db-1", 0, 1.0),
+                point("db-2", 0, 2.0),
+                point("db-3", 0, 3.0),
+            ])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+
+        // 첫 요청은 503으로 실패하여 같은 배치를 다시 보냅니다
+        let requests = receiver.requests();
+        let writes: Vec<_> = requests.iter().map(write_request).collect();
+        let sizes: Vec<_> = writes.iter().map(|w| w.timeseries.len()).collect();
+        assert_eq!(sizes, vec![2, 2, 1]);
+        assert_eq!(writes[0], writes[1]);
+
+        let headers = &requests[1].headers;
+        assert_eq!(requests[1].path, "/api/v1/write");
+        assert_eq!(headers["authorization"], "Bearer secret");
+        assert_eq!(headers["content-encoding"], "snappy");
+        let request = &writes[1];
+        let series = &request.timeseries[0];
+        let labels: Vec<_> = series
+            .labels
+            .iter()
+            .map(|label| (label.name.as_str(), label.value.as_str()))
+            .collect();
+        // 빈 값 레이블(account_id)은 보내지 않습니다
+        assert_eq!(
+            labels,
+            vec![
+                ("__name__", "rds_cpuutilization"),
+                ("db_instance_identifier", "db-1"),
+                ("engine", "mysql"),
+                ("stat", "Average"),
+            ]
+        );
+        assert_eq!(
+            series.samples,
+            vec![Sample {
+                value: 1.0,
+                timestamp: 1735689600000
+            }]
+        );
+
+        // 같은 데이터 포인트는 다시 보내지 않습니다
+        publisher
+            .publish(vec![point("db-1", 0, 1.0)])
+            .await
+            .unwrap();
+        publisher.flush().await.unwrap();
+        assert_eq!(receiver.requests().len(), 3);
+    }
+
+    #[tokio::test]
+    async fn test_rejected_batches_are_dropped_and_queue_is_bounded() {
+        let (receiver, url) = HttpReceiver::start(&[400], 204);
+        let publisher = publisher(url, 10, 2);
+        let dropped = || {
+            PUBLISHER_DROPPED_SAMPLES
+                .with_label_values(&[PUBLISHER])
+                .get()
+        };
+        let dropped_before = dropped();
+
+        // 큐 용량 2를 넘는 가장 오래된 샘플은 버립니다
+        publisher.inner.enqueue(&[
+            point("db-1", 0, 1.0),
+            point("db-2", 0, 2.0),
+            point("db-3", 0, 3.0),
+        ]);
+        assert_eq!(publisher.inner.queue.len(), 2);
+
+        // 400 응답은 재시도하지 않고 배치를 버립니다
+        publisher.flush().await.unwrap();
+        assert_eq!(receiver.requests().len(), 1);
+        assert_eq!(publisher.inner.queue.len(), 0);
+        assert!(dropped() >= dropped_before + 3);
+    }
+
+    #[tokio::test]
+    async fn test_forgets_series_not_published_for_stale_cycles() {
+        let (_, url) = HttpReceiver::start(&[], 204);
+        let publisher = publisher(url, 10, 100);
+        let remembered = || publisher.inner.sent.lock().last.len();
+
+        publisher
+            .inner
+            .enqueue(&[point("db-1", 0, 1.0), point("db-2", 0, 2.0)]);
+        assert_eq!(remembered(), 2);
+
+        // db-2는 같은 데이터 포인트라 다시 큐에 넣지 않지만 발행된 것으로 봅니다
+        publisher.inner.enqueue(&[point("db-2", 0, 2.0)]);
+        publisher.inner.enqueue(&[point("db-2", 1, 2.0)]);
+        assert_eq!(remembered(), 2);
+        publisher.inner.enqueue(&[point("db-2", 2, 2.0)]);
+        assert_eq!(remembered(), 1);
+        assert_eq!(publisher.inner.queue.len(), 4);
+    }
+
+    #[tokio::test]
+    async fn test_remembers_all_series_when_stale_cycles_is_zero() {
+        let (_, url) = HttpReceiver::start(&[], 204);
+        let publisher = RemoteWritePublisher::new(RemoteWriteConfig {
+            stale_after_cycles: 0,
+            ..publisher(url, 10, 100).inner.config.clone()
+        })
+        .unwrap();
+
+        publisher
+            .inner
+            .enqueue(&[point("db-1", 0, 1.0), point("db-2", 0, 2.0)]);
+        for minute in 1..5 {
+            publisher.inner.enqueue(&[point("db-2", minute, 2.0)]);
+        }
+        assert_eq!(publisher.inner.sent.lock().last.len(), 2);
+
+        // db-1의 같은 데이터 포인트는 다시 보내지 않습니다
+        publisher.inner.enqueue(&[point("db-1", 0, 1.0)]);
+        assert_eq!(publisher.inner.queue.len(), 6);
+    }
+
+    #[test]
+    fn test_config_from_settings() {
+        let publishers = PublishersSettings::example();
+        let config = RemoteWriteConfig::from_settings(
+            publishers.remote_write.as_ref().unwrap(),
+            StatStyle::Suffix,
+            3,
+        );
+
+        assert_eq!(config.http.url, "http://prometheus:9090/api/v1/write");
+        assert_eq!(config.http.timeout, Duration::from_secs(10));
+        assert_eq!(
+            config.http.auth,
+            Some(HttpAuth::Basic {
+                username: "rds".to_string(),
+                password: "secret".to_string(),
+            })
+        );
+        assert_eq!(config.stat_style, StatStyle::Suffix);
+        assert_eq!(config.stale_after_cycles, 3);
+    }
+}
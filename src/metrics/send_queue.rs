@@ -0,0 +1,93 @@
+// src/metrics/send_queue.rs
+use crate::exporter_metrics::{PUBLISHER_DROPPED_SAMPLES, PUBLISHER_QUEUE_LENGTH};
+use parking_lot::Mutex;
+use std::collections::VecDeque;
+use std::future::Future;
+use tracing::warn;
+
+/// 푸시 발행자가 수집 주기와 별도로 백그라운드에서 보낼 항목의 메모리 큐
+///
+/// 발행자의 `publish`는 항목을 큐에 넣고 백그라운드에서 비우므로 수집 주기를 막지 않습니다.
+/// 재시도 후에도 실패한 배치는 큐에 남아 다음 주기에 다시 전송됩니다.
+/// 용량을 넘으면 가장 오래된 항목부터 버리고, 큐는 한 번에 한 곳에서만 비웁니다.
+pub struct SendQueue<T> {
+    /// 자체 메트릭의 `publisher` 레이블
+    publisher: &'static str,
+    capacity: usize,
+    items: Mutex<VecDeque<T>>,
+    /// 동시에 한 곳에서만 큐를 비우도록 직렬화합니다
+    send_lock: tokio::sync::Mutex<()>,
+}
+
+impl<T: Clone> SendQueue<T> {
+    pub fn new(publisher: &'static str, capacity: usize) -> Self {
+        Self {
+            publisher,
+            capacity,
+            items: Mutex::new(VecDeque::new()),
+            send_lock: tokio::sync::Mutex::new(()),
+        }
+    }
+
+    #[cfg(test)]
+    pub fn len(&self) -> usize {
+        self.items.lock().len()
+    }
+
+    pub fn push(&self, items: impl IntoIterator<Item = T>) {
+        let mut queue = self.items.lock();
+        queue.extend(items);
+
+        let overflow = queue.len().saturating_sub(self.capacity);
+        if overflow > 0 {
+            queue.drain(..overflow);
+            PUBLISHER_DROPPED_SAMPLES
+                .with_label_values(&[self.publisher])
+                .inc_by(overflow as u64);
+            warn!(
+                "{} 큐가 가득 차 오래된 항목 {} 개를 버립니다",
+                self.publisher, overflow
+            );
+        }
+        self.set_length(queue.len());
+    }
+
+    /// 큐가 빌 때까지 `batch_size`개씩 `send`로 보냅니다.
+    ///
+    /// `send`가 오류를 반환하면 배치를 큐 앞에 되돌리고 멈춥니다. 다시 보내도 실패할 배치는
+    /// `send`가 버린 수를 기록한 뒤 `Ok`를 반환해야 합니다.
+    pub async fn drain<F, Fut, E>(&self, batch_size: usize, mut send: F) -> Result<(), E>
+    where
+        F: FnMut(Vec<T>) -> Fut,
+        Fut: Future<Output = Result<(), E>>,
+    {
+        let _guard = self.send_lock.lock().await;
+
+        loop {
+            let batch: Vec<T> = {
+                let mut queue = self.items.lock();
+                let size = queue.len().min(batch_size);
+                queue.drain(..size).collect()
+            };
+            if batch.is_empty() {
+                return Ok(());
+            }
+
+            let result = send(batch.clone()).await;
+            let mut queue = self.items.lock();
+            if result.is_err() {
+                for item in batch.into_iter().rev() {
+                    queue.push_front(item);
+                }
+            }
+            self.set_length(queue.len());
+            result?;
+        }
+    }
+
+    fn set_length(&self, length: usize) {
+        PUBLISHER_QUEUE_LENGTH
+            .with_label_values(&[self.publisher])
+            .set(length as i64);
+    }
+}
@@ -0,0 +1,289 @@
+// src/metrics/statsd_publisher.rs
+use crate::config::{StatStyle, StatsdSettings};
+use crate::exporter_metrics::PUBLISHER_DROPPED_SAMPLES;
+use crate::metrics::collector::{MetricKind, MetricPoint, MetricPublisher};
+use crate::metrics::prometheus_publisher::metric_name;
+use crate::metrics::udp_sender::UdpSender;
+use async_trait::async_trait;
+use tracing::debug;
+
+const PUBLISHER: &str = "statsd";
+
+/// 일반 StatsD 이름에 넣는 대상 구분 레이블 (값이 있는 것만, 이 순서로)
+const NAME_LABELS: [&str; 4] = [
+    "account_id",
+    "region",
+    "db_instance_identifier",
+    "db_cluster_identifier",
+];
+
+#[derive(Debug, Clone)]
+pub struct StatsdConfig {
+    /// StatsD 서버 주소 (`호스트:포트`)
+    pub address: String,
+    /// 모든 메트릭 이름 앞에 `.`으로 붙일 접두사 (빈 문자열이면 생략)
+    pub prefix: String,
+    /// DogStatsD 태그(`|#key:value`)로 레이블을 보낼지 여부
+    pub dogstatsd: bool,
+    /// UDP 패킷 하나의 최대 크기 (바이트)
+    pub max_packet_size: usize,
+    pub stat_style: StatStyle,
+}
+
+impl StatsdConfig {
+    pub fn from_settings(settings: &StatsdSettings, stat_style: StatStyle) -> Self {
+        Self {
+            address: settings.address.clone(),
+            prefix: settings.prefix.clone(),
+            dogstatsd: settings.dogstatsd,
+            max_packet_size: settings.max_packet_size,
+            stat_style,
+        }
+    }
+}
+
+/// `MetricPoint`를 StatsD gauge로 보내는 발행자
+///
+/// DogStatsD 모드에서는 메트릭 이름이 `/metrics`와 같고 레이블은 태그로 보냅니다.
+/// 일반 StatsD는 태그가 없으므로 계정, 리전, 식별자와 통계를 이름에 넣고
+/// (`rds.ap-northeast-2.db-1.rds_cpuutilization_average`), 레이블로만 구분되는 정보 메트릭은 보내지 않습니다.
+pub struct StatsdPublisher {
+    sender: UdpSender,
+    config: StatsdConfig,
+}
+
+impl StatsdPublisher {
+    pub fn new(config: StatsdConfig) -> std::io::Result<Self> {
+        Ok(Self {
+            sender: UdpSender::connect(PUBLISHER, &config.address, config.max_packet_size)?,
+            config,
+        })
+    }
+
+    fn name(&self, metric: &MetricPoint) -> Option<String> {
+        let mut segments = Vec::new();
+        if !self.config.prefix.is_empty() {
+            segments.push(sanitize_name(&self.config.prefix, false));
+        }
+
+        if self.config.dogstatsd {
+            segments.push(sanitize_name(
+                &metric_name(metric, self.config.stat_style),
+                false,
+            ));
+        } else {
+            if metric.kind == MetricKind::Info {
+                return None;
+            }
+            for label in NAME_LABELS {
+                match metric.additional_tags.get(label) {
+                    Some(value) if !value.is_empty() => segments.push(sanitize_name(value, true)),
+                    _ => {}
+                }
+            }
+            // 태그가 없으므로 통계는 항상 이름에 붙입니다
+            segments.push(sanitize_name(&metric_name(metric, StatStyle::Suffix), true));
+        }
+
+        Some(segments.join("."))
+    }
+
+    fn tags(&self, metric: &MetricPoint) -> String {
+        let mut tags: Vec<(&str, &str)> = metric
+            .additional_tags
+            .iter()
+            .filter(|(_, value)| !value.is_empty())
+            .map(|(name, value)| (name.as_str(), value.as_str()))
+            .collect();
+        if metric.kind == MetricKind::CloudWatch
+            && self.config.stat_style == StatStyle::Label
+            && !metric.stat.is_empty()
+        {
+            tags.push(("stat", &metric.stat));
+        }
+        tags.sort();
+
+        tags.iter()
+            .map(|(name, value)| format!("{}:{}", sanitize_tag(name), sanitize_tag(value)))
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    /// 데이터 포인트 하나의 gauge 줄.
+    ///
+    /// StatsD는 부호가 붙은 gauge 값을 이전 값에 대한 증감으로 해석하므로,
+    /// 음수는 0으로 맞춘 뒤 빼는 두 줄을 한 패킷에 담기도록 개행으로 이어 반환합니다.
+    fn line(&self, metric: &MetricPoint) -> Option<String> {
+        if !metric.value.is_finite() {
+            return None;
+        }
+        let name = self.name(metric)?;
+        let tags = if self.config.dogstatsd {
+            match self.tags(metric) {
+                tags if tags.is_empty() => String::new(),
+                tags => format!("|#{}", tags),
+            }
+        } else {
+            String::new()
+        };
+
+        let gauge = format!("{}:{}|g{}", name, metric.value, tags);
+        if metric.value < 0.0 {
+            Some(format!("{}:0|g{}\n{}", name, tags, gauge))
+        } else {
+            Some(gauge)
+        }
+    }
+}
+
+#[async_trait]
+impl MetricPublisher for StatsdPublisher {
+    async fn publish(&self, metrics: Vec<MetricPoint>) -> anyhow::Result<()> {
+        let lines: Vec<String> = metrics.iter().filter_map(|m| self.line(m)).collect();
+        if lines.len() < metrics.len() {
+            debug!(
+                "StatsD로 보낼 수 없는 데이터 포인트(NaN, 무한대, 정보 메트릭) {} 개를 건너뜁니다",
+                metrics.len() - lines.len()
+            );
+        }
+
+        if let Err(e) = self.sender.send_lines(&lines).await {
+            PUBLISHER_DROPPED_SAMPLES
+                .with_label_values(&[PUBLISHER])
+                .inc_by(e.lines as u64);
+            return Err(e.into());
+        }
+        debug!("StatsD 전송 완료: {} 개", lines.len());
+        Ok(())
+    }
+
+    fn gather(&self) -> Vec<prometheus::proto::MetricFamily> {
+        Vec::new()
+    }
+}
+
+/// StatsD 프로토콜 구분 문자를 `_`로 바꿉니다. `segment`이면 이름 계층 구분자인 `.`도 바꿉니다.
+fn sanitize_name(value: &str, segment: bool) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ':' | '|' | '@' | '#' | ',' | ' ' | '\n' | '\r' => '_',
+            '.' if segment => '_',
+            c => c,
+        })
+        .collect()
+}
+
+/// DogStatsD 태그 구분 문자를 `_`로 바꿉니다.
+fn sanitize_tag(value: &str) -> String {
+    value
+        .chars()
+        .map(|c| match c {
+            ',' | '|' | '#' | '\n' | '\r' => '_',
+            c => c,
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::metrics::test_util::{self, cpu_point};
+
+    const TAGS: [(&str, &str); 3] = [
+        ("region", "ap-northeast-2"),
+        ("engine", "mysql"),
+        ("account_id", ""),
+    ];
+
+    fn point(instance: &str, value: f64) -> MetricPoint {
+        let mut tags = vec![("db_instance_identifier", instance)];
+        tags.extend(TAGS);
+        cpu_point(value, &tags)
+    }
+
+    fn info_point() -> MetricPoint {
+        let mut tags = vec![("db_instance_identifier", "db-1")];
+        tags.extend(TAGS);
+        test_util::info_point("instance_info", &tags)
+    }
+
+    fn publisher(address: String, dogstatsd: bool, max_packet_size: usize) -> StatsdPublisher {
+        StatsdPublisher::new(StatsdConfig {
+            address,
+            prefix: "dba".to_string(),
+            dogstatsd,
+            max_packet_size,
+            stat_style: StatStyle::Label,
+        })
+        .unwrap()
+    }
+
+    #[tokio::test]
+    async fn test_gauge_lines() {
+        let plain = publisher("127.0.0.1:8125".to_string(), false, 1432);
+        assert_eq!(
+            plain.line(&point("db-1", 12.5)).unwrap(),
+            "dba.ap-northeast-2.db-1.rds_cpuutilization_average:12.5|g"
+        );
+        assert_eq!(
+            plain.line(&point("db.1", 3.0)).unwrap(),
+            "dba.ap-northeast-2.db_1.rds_cpuutilization_average:3|g"
+        );
+        // 음수는 0으로 맞춘 뒤 뺍니다
+        assert_eq!(
+            plain.line(&point("db-1", -2.0)).unwrap(),
+            "dba.ap-northeast-2.db-1.rds_cpuutilization_average:0|g\n\
+             dba.ap-northeast-2.db-1.rds_cpuutilization_average:-2|g"
+        );
+        assert!(plain.line(&info_point()).is_none());
+        assert!(plain.line(&point("db-1", f64::INFINITY)).is_none());
+
+        let dogstatsd = publisher("127.0.0.1:8125".to_string(), true, 1432);
+        assert_eq!(
+            dogstatsd.line(&point("db-1", 12.5)).unwrap(),
+            "dba.rds_cpuutilization:12.5|g|#db_instance_identifier:db-1,engine:mysql,\
+             region:ap-northeast-2,stat:Average"
+        );
+        assert_eq!(
+            dogstatsd.line(&info_point()).unwrap(),
+            "dba.rds_instance_info:1|g|#db_instance_identifier:db-1,engine:mysql,\
+             region:ap-northeast-2"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_coalesces_lines_into_packets() {
+        let receiver = tokio::net::UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let line_length = "dba.ap-northeast-2.db-1.rds_cpuutilization_average:1|g".len();
+        let publisher = publisher(
+            receiver.local_addr().unwrap().to_string(),
+            false,
+            line_length * 2 + 1,
+        );
+
+        publisher
+            .publish(vec![
+                point("db-1", 1.0),
+                point("db-2", 2.0),
+                point("db-3", 3.0),
+            ])
+            .await
+            .unwrap();
+
+        let mut buffer = [0u8; 2048];
+        let mut packets = Vec::new();
+        for _ in 0..2 {
+            let size = receiver.recv(&mut buffer).await.unwrap();
+            packets.push(String::from_utf8(buffer[..size].to_vec()).unwrap());
+        }
+        assert_eq!(
+            packets,
+            vec![
+                "dba.ap-northeast-2.db-1.rds_cpuutilization_average:1|g\n\
+                 dba.ap-northeast-2.db-2.rds_cpuutilization_average:2|g",
+                "dba.ap-northeast-2.db-3.rds_cpuutilization_average:3|g",
+            ]
+        );
+    }
+}
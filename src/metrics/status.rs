@@ -0,0 +1,216 @@
+// src/metrics/status.rs
+use crate::exporter_metrics::LAST_SUCCESSFUL_CYCLE;
+use chrono::{DateTime, Duration, Utc};
+use parking_lot::RwLock;
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+/// 수집 대상 하나에서 상태를 추적하는 구성 요소
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Component {
+    /// 인스턴스/클러스터 조회 (DescribeDBInstances, DescribeDBClusters)
+    Rds,
+    /// GetMetricData 조회
+    CloudWatch,
+}
+
+impl Component {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Component::Rds => "rds",
+            Component::CloudWatch => "cloudwatch",
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+struct ComponentState {
+    last_success: Option<DateTime<Utc>>,
+    last_error: Option<(DateTime<Utc>, String)>,
+    /// 마지막 시도가 성공했는지 여부
+    healthy: bool,
+}
+
+#[derive(Debug, Default)]
+struct StatusState {
+    last_success: Option<DateTime<Utc>>,
+    /// (account_id, region, 구성 요소) -> 상태
+    components: BTreeMap<(String, String, Component), ComponentState>,
+}
+
+/// 수집기가 기록하고 HTTP 엔드포인트가 읽는 수집 상태
+#[derive(Debug, Clone, Default)]
+pub struct CollectionStatus {
+    state: Arc<RwLock<StatusState>>,
+}
+
+impl CollectionStatus {
+    pub fn record_success(&self, account_id: &str, region: &str, component: Component) {
+        let mut state = self.state.write();
+        let entry = state
+            .components
+            .entry((account_id.to_string(), region.to_string(), component))
+            .or_default();
+        entry.last_success = Some(Utc::now());
+        entry.healthy = true;
+    }
+
+    pub fn record_failure(
+        &self,
+        account_id: &str,
+        region: &str,
+        component: Component,
+        error: impl std::fmt::Display,
+    ) {
+        let mut state = self.state.write();
+        let entry = state
+            .components
+            .entry((account_id.to_string(), region.to_string(), component))
+            .or_default();
+        entry.last_error = Some((Utc::now(), error.to_string()));
+        entry.healthy = false;
+    }
+
+    /// 수집 주기 하나가 CloudWatch 데이터를 가지고 끝났음을 기록합니다.
+    /// `/ready`와 `rds_exporter_last_successful_cycle_timestamp_seconds`가 같은 기준을 따르도록 함께 갱신합니다.
+    pub fn record_cycle_success(&self, at: DateTime<Utc>) {
+        self.state.write().last_success = Some(at);
+        LAST_SUCCESSFUL_CYCLE.set(at.timestamp() as f64);
+    }
+
+    /// 첫 수집에 성공했고 마지막 성공이 `max_age` 이내이면 준비 상태입니다.
+    pub fn is_ready(&self, now: DateTime<Utc>, max_age: Duration) -> bool {
+        self.state
+            .read()
+            .last_success
+            .is_some_and(|last_success| now - last_success <= max_age)
+    }
+
+    pub fn report(&self, now: DateTime<Utc>, max_age: Duration) -> HealthReport {
+        let state = self.state.read();
+
+        let mut targets: BTreeMap<(String, String), TargetReport> = BTreeMap::new();
+        for ((account_id, region, component), component_state) in &state.components {
+            targets
+                .entry((account_id.clone(), region.clone()))
+                .or_insert_with(|| TargetReport {
+                    account_id: account_id.clone(),
+                    region: region.clone(),
+                    components: BTreeMap::new(),
+                })
+                .components
+                .insert(
+                    component.as_str(),
+                    ComponentReport {
+                        healthy: component_state.healthy,
+                        last_success: component_state.last_success.map(|t| t.to_rfc3339()),
+                        last_error: component_state
+                            .last_error
+                            .as_ref()
+                            .map(|(_, message)| message.clone()),
+                        last_error_at: component_state
+                            .last_error
+                            .as_ref()
+                            .map(|(at, _)| at.to_rfc3339()),
+                    },
+                );
+        }
+
+        let status = match state.last_success {
+            None => "starting",
+            Some(last_success) if now - last_success > max_age => "stale",
+            Some(_)
+                if targets
+                    .values()
+                    .flat_map(|target| target.components.values())
+                    .any(|component| !component.healthy) =>
+            {
+                "degraded"
+            }
+            Some(_) => "ok",
+        };
+
+        HealthReport {
+            status,
+            last_success: state.last_success.map(|t| t.to_rfc3339()),
+            targets: targets.into_values().collect(),
+        }
+    }
+}
+
+/// `/health` 응답 본문
+#[derive(Debug, Serialize)]
+pub struct HealthReport {
+    /// `starting`(첫 수집 전), `ok`, `degraded`(일부 구성 요소 실패), `stale`(마지막 성공이 오래됨)
+    pub status: &'static str,
+    pub last_success: Option<String>,
+    pub targets: Vec<TargetReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct TargetReport {
+    pub account_id: String,
+    pub region: String,
+    pub components: BTreeMap<&'static str, ComponentReport>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ComponentReport {
+    pub healthy: bool,
+    pub last_success: Option<String>,
+    pub last_error: Option<String>,
+    pub last_error_at: Option<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_readiness_follows_last_success() {
+        let status = CollectionStatus::default();
+        let now = Utc::now();
+        let max_age = Duration::minutes(3);
+
+        assert!(!status.is_ready(now, max_age));
+        assert_eq!(status.report(now, max_age).status, "starting");
+
+        status.record_cycle_success(now);
+        assert!(status.is_ready(now + Duration::minutes(1), max_age));
+        assert!(!status.is_ready(now + Duration::minutes(4), max_age));
+        assert_eq!(
+            status.report(now + Duration::minutes(4), max_age).status,
+            "stale"
+        );
+    }
+
+    #[test]
+    fn test_report_lists_components_per_target() {
+        let status = CollectionStatus::default();
+        let now = Utc::now();
+        status.record_cycle_success(now);
+        status.record_success("", "ap-northeast-2", Component::Rds);
+        status.record_success("", "ap-northeast-2", Component::CloudWatch);
+        status.record_success("111111111111", "us-east-1", Component::Rds);
+        status.record_failure(
+            "111111111111",
+            "us-east-1",
+            Component::CloudWatch,
+            "AccessDenied",
+        );
+
+        let report = status.report(now, Duration::minutes(3));
+        assert_eq!(report.status, "degraded");
+        assert_eq!(report.targets.len(), 2);
+
+        let failed = &report.targets[1];
+        assert_eq!(failed.account_id, "111111111111");
+        assert!(failed.components["rds"].healthy);
+        assert!(!failed.components["cloudwatch"].healthy);
+        assert_eq!(
+            failed.components["cloudwatch"].last_error.as_deref(),
+            Some("AccessDenied")
+        );
+    }
+}
@@ -0,0 +1,129 @@
+// src/metrics/test_util.rs
+use crate::metrics::collector::{MetricKind, MetricPoint, MetricScope};
+use crate::metrics::http_sender::HttpSenderConfig;
+use chrono::{TimeZone, Utc};
+use parking_lot::Mutex;
+use std::collections::{HashMap, VecDeque};
+use std::sync::Arc;
+use std::time::Duration;
+use warp::http::{HeaderMap, Method, StatusCode};
+use warp::hyper::body::Bytes;
+use warp::Filter;
+
+/// 2025-01-01 00:00 UTC의 인스턴스 `CPUUtilization` 평균 데이터 포인트
+pub fn cpu_point(value: f64, tags: &[(&str, &str)]) -> MetricPoint {
+    MetricPoint {
+        value,
+        timestamp: Utc.with_ymd_and_hms(2025, 1, 1, 0, 0, 0).unwrap(),
+        metric_name: "CPUUtilization".to_string(),
+        stat: "Average".to_string(),
+        scope: MetricScope::Instance,
+        kind: MetricKind::CloudWatch,
+        additional_tags: tags
+            .iter()
+            .map(|(key, value)| (key.to_string(), value.to_string()))
+            .collect::<HashMap<_, _>>(),
+    }
+}
+
+/// `cpu_point`와 같은 시각과 범위의 Info 메트릭 데이터 포인트
+pub fn info_point(metric_name: &str, tags: &[(&str, &str)]) -> MetricPoint {
+    MetricPoint {
+        value: 1.0,
+        metric_name: metric_name.to_string(),
+        stat: String::new(),
+        kind: MetricKind::Info,
+        ..cpu_point(1.0, tags)
+    }
+}
+
+/// `cpu_point`에 인스턴스 식별자와 mysql 엔진, 빈 계정 ID 태그를 붙인 데이터 포인트
+pub fn instance_point(instance: &str, value: f64) -> MetricPoint {
+    cpu_point(
+        value,
+        &[
+            ("db_instance_identifier", instance),
+            ("engine", "mysql"),
+            ("account_id", ""),
+        ],
+    )
+}
+
+/// 인증 없이 세 번까지 1ms 간격으로 재시도하는 HTTP 전송 설정
+pub fn http_config(url: String) -> HttpSenderConfig {
+    HttpSenderConfig {
+        url,
+        auth: None,
+        max_retries: 3,
+        retry_delay: Duration::from_millis(1),
+        timeout: Duration::from_secs(5),
+    }
+}
+
+/// [`HttpReceiver`]가 받은 요청
+#[derive(Debug, Clone)]
+pub struct CapturedRequest {
+    pub method: Method,
+    pub path: String,
+    pub query: HashMap<String, String>,
+    pub headers: HeaderMap,
+    pub body: Bytes,
+}
+
+impl CapturedRequest {
+    pub fn text(&self) -> String {
+        String::from_utf8(self.body.to_vec()).unwrap()
+    }
+}
+
+/// 받은 요청을 기록하고 준비된 상태 코드를 순서대로 돌려주는 테스트용 HTTP 수신기
+#[derive(Clone)]
+pub struct HttpReceiver {
+    statuses: Arc<Mutex<VecDeque<u16>>>,
+    requests: Arc<Mutex<Vec<CapturedRequest>>>,
+}
+
+impl HttpReceiver {
+    /// 준비된 상태 코드를 다 쓰면 `default_status`로 응답합니다.
+    /// 수신기와 함께 `http://주소` 형태의 기본 URL을 돌려줍니다.
+    pub fn start(statuses: &[u16], default_status: u16) -> (Self, String) {
+        let receiver = Self {
+            statuses: Arc::new(Mutex::new(statuses.iter().copied().collect())),
+            requests: Arc::new(Mutex::new(Vec::new())),
+        };
+        let state = receiver.clone();
+        let route = warp::method()
+            .and(warp::path::full())
+            .and(warp::query::<HashMap<String, String>>())
+            .and(warp::header::headers_cloned())
+            .and(warp::body::bytes())
+            .map(
+                move |method: Method,
+                      path: warp::path::FullPath,
+                      query: HashMap<String, String>,
+                      headers: HeaderMap,
+                      body: Bytes| {
+                    state.requests.lock().push(CapturedRequest {
+                        method,
+                        path: path.as_str().to_string(),
+                        query,
+                        headers,
+                        body,
+                    });
+                    let status = state.statuses.lock().pop_front().unwrap_or(default_status);
+                    warp::reply::with_status(warp::reply(), StatusCode::from_u16(status).unwrap())
+                },
+            );
+        let (addr, server) = warp::serve(route).bind_ephemeral(([127, 0, 0, 1], 0));
+        tokio::spawn(server);
+        (receiver, format!("http://{}", addr))
+    }
+
+    pub fn requests(&self) -> Vec<CapturedRequest> {
+        self.requests.lock().clone()
+    }
+
+    pub fn clear(&self) {
+        self.requests.lock().clear();
+    }
+}
@@ -0,0 +1,140 @@
+// src/metrics/udp_sender.rs
+use crate::exporter_metrics::PUBLISHER_REQUESTS;
+use std::net::{ToSocketAddrs, UdpSocket as StdUdpSocket};
+use thiserror::Error;
+use tokio::net::UdpSocket;
+use tracing::warn;
+
+#[derive(Error, Debug)]
+#[error("UDP 전송 실패 ({lines} 줄): {source}")]
+pub struct UdpSendError {
+    /// 보내지 못한 패킷에 담긴 줄 수
+    pub lines: usize,
+    source: std::io::Error,
+}
+
+/// 줄 단위 텍스트 프로토콜(InfluxDB line protocol, StatsD)을 UDP로 보내는 전송기
+///
+/// 여러 줄을 `max_packet_size` 바이트 이하의 패킷 하나에 개행으로 이어 붙여 보냅니다.
+pub struct UdpSender {
+    /// 자체 메트릭의 `publisher` 레이블
+    publisher: &'static str,
+    socket: UdpSocket,
+    max_packet_size: usize,
+}
+
+impl UdpSender {
+    /// `address`(`호스트:포트`)를 한 번 해석하여 연결된 소켓을 만듭니다.
+    pub fn connect(
+        publisher: &'static str,
+        address: &str,
+        max_packet_size: usize,
+    ) -> std::io::Result<Self> {
+        let target = address.to_socket_addrs()?.next().ok_or_else(|| {
+            std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("주소를 찾을 수 없습니다: {}", address),
+            )
+        })?;
+        let local = if target.is_ipv4() {
+            "0.0.0.0:0"
+        } else {
+            "[::]:0"
+        };
+        let socket = StdUdpSocket::bind(local)?;
+        socket.connect(target)?;
+        socket.set_nonblocking(true)?;
+
+        Ok(Self {
+            publisher,
+            socket: UdpSocket::from_std(socket)?,
+            max_packet_size,
+        })
+    }
+
+    /// 줄을 패킷으로 묶어 보냅니다. 보내지 못한 패킷이 있으면 그 줄 수와 마지막 오류를 반환합니다.
+    /// 개행이 들어 있는 줄은 나누지 않고 한 패킷에 담습니다.
+    pub async fn send_lines(&self, lines: &[String]) -> Result<(), UdpSendError> {
+        let mut failed = 0;
+        let mut last_error = None;
+
+        for (packet, count) in pack_lines(lines, self.max_packet_size) {
+            match self.socket.send(packet.as_bytes()).await {
+                Ok(_) => {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[self.publisher, "success"])
+                        .inc();
+                }
+                Err(e) => {
+                    PUBLISHER_REQUESTS
+                        .with_label_values(&[self.publisher, "error"])
+                        .inc();
+                    warn!("{} UDP 전송 실패: {}", self.publisher, e);
+                    failed += count;
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        match last_error {
+            Some(source) => Err(UdpSendError {
+                lines: failed,
+                source,
+            }),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 줄을 개행으로 이어 `max_size` 바이트 이하의 패킷으로 묶고, 패킷마다 담긴 줄 수를 함께 반환합니다.
+/// 한 줄이 `max_size`보다 길면 그 줄만 담은 패킷을 만듭니다.
+fn pack_lines(lines: &[String], max_size: usize) -> Vec<(String, usize)> {
+    let mut packets = Vec::new();
+    let mut packet = String::new();
+    let mut count = 0;
+
+    for line in lines {
+        if count > 0 && packet.len() + 1 + line.len() > max_size {
+            packets.push((std::mem::take(&mut packet), count));
+            count = 0;
+        }
+        if count > 0 {
+            packet.push('\n');
+        }
+        packet.push_str(line);
+        count += 1;
+    }
+    if count > 0 {
+        packets.push((packet, count));
+    }
+
+    packets
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pack_lines_under_max_size() {
+        let lines: Vec<String> = ["aaaa", "bbbb", "cccc", "dddddddddddd", "ee"]
+            .iter()
+            .map(|line| line.to_string())
+            .collect();
+
+        assert_eq!(
+            pack_lines(&lines, 9),
+            vec![
+                ("aaaa\nbbbb".to_string(), 2),
+                ("cccc".to_string(), 1),
+                ("dddddddddddd".to_string(), 1),
+                ("ee".to_string(), 1),
+            ]
+        );
+        assert_eq!(
+            pack_lines(&lines, 100),
+            vec![("aaaa\nbbbb\ncccc\ndddddddddddd\nee".to_string(), 5)]
+        );
+        assert!(pack_lines(&[], 100).is_empty());
+    }
+}
@@ -0,0 +1,154 @@
+// src/reload.rs
+use crate::config::Settings;
+use crate::exporter_metrics::{CONFIG_LAST_RELOAD_SUCCESSFUL, CONFIG_RELOADS};
+use notify::{RecursiveMode, Watcher};
+use parking_lot::Mutex;
+use std::path::Path;
+use std::sync::Arc;
+use tokio::sync::{mpsc, watch};
+use tracing::{error, info, warn};
+
+/// 편집기가 한 번 저장할 때 발생하는 여러 파일 이벤트를 하나로 묶는 시간
+const FILE_EVENT_DEBOUNCE: std::time::Duration = std::time::Duration::from_millis(500);
+
+/// 설정 파일을 다시 읽어 수집기에 전달합니다.
+///
+/// 파일 변경, `POST /-/reload`, SIGHUP 모두 [`ConfigReloader::reload`]를 호출하며,
+/// 검증에 실패했거나 재시작해야 적용되는 섹션이 바뀐 설정은 전달하지 않고 기존 설정을 유지합니다.
+pub struct ConfigReloader {
+    sender: watch::Sender<Arc<Settings>>,
+    /// 동시에 들어온 재적용 요청을 직렬화합니다
+    lock: Mutex<()>,
+}
+
+impl ConfigReloader {
+    pub fn new(initial: Arc<Settings>) -> (Self, watch::Receiver<Arc<Settings>>) {
+        let (sender, receiver) = watch::channel(initial);
+        CONFIG_LAST_RELOAD_SUCCESSFUL.set(1.0);
+        (
+            Self {
+                sender,
+                lock: Mutex::new(()),
+            },
+            receiver,
+        )
+    }
+
+    pub fn reload(&self) -> anyhow::Result<()> {
+        self.apply(Settings::new().map_err(anyhow::Error::from))
+    }
+
+    /// 파일 읽기와 YAML 해석이 런타임 작업 스레드를 막지 않도록 블로킹 스레드에서 [`reload`](Self::reload)를 실행합니다.
+    pub async fn reload_blocking(self: &Arc<Self>) -> anyhow::Result<()> {
+        let reloader = Arc::clone(self);
+        tokio::task::spawn_blocking(move || reloader.reload()).await?
+    }
+
+    fn apply(&self, settings: anyhow::Result<Settings>) -> anyhow::Result<()> {
+        let _guard = self.lock.lock();
+
+        let settings = settings.and_then(|settings| {
+            let changed = self.sender.borrow().restart_required_changes(&settings);
+            if changed.is_empty() {
+                Ok(settings)
+            } else {
+                Err(anyhow::anyhow!(
+                    "재시작해야 적용되는 설정이 바뀌었습니다: {}",
+                    changed.join(", ")
+                ))
+            }
+        });
+
+        match settings {
+            Ok(settings) => {
+                self.sender.send_replace(Arc::new(settings));
+                CONFIG_RELOADS.with_label_values(&["success"]).inc();
+                CONFIG_LAST_RELOAD_SUCCESSFUL.set(1.0);
+                info!("설정 다시 읽기 완료, 다음 수집 주기부터 적용됩니다");
+                Ok(())
+            }
+            Err(e) => {
+                CONFIG_RELOADS.with_label_values(&["failure"]).inc();
+                CONFIG_LAST_RELOAD_SUCCESSFUL.set(0.0);
+                error!("설정 다시 읽기 실패, 기존 설정을 유지합니다: {}", e);
+                Err(e)
+            }
+        }
+    }
+
+    /// `dir` 아래 파일이 바뀌면 설정을 다시 읽습니다. 반환된 감시자가 살아 있는 동안 동작합니다.
+    pub fn watch_files(
+        self: &Arc<Self>,
+        dir: impl AsRef<Path>,
+    ) -> anyhow::Result<notify::RecommendedWatcher> {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<notify::Event>| match event {
+                Ok(event) if event.kind.is_create() || event.kind.is_modify() => {
+                    let _ = tx.send(());
+                }
+                Ok(_) => {}
+                Err(e) => warn!("설정 파일 감시 오류: {}", e),
+            })?;
+        watcher.watch(dir.as_ref(), RecursiveMode::NonRecursive)?;
+        info!("설정 파일 감시 시작: {}", dir.as_ref().display());
+
+        let reloader = Arc::clone(self);
+        tokio::spawn(async move {
+            while rx.recv().await.is_some() {
+                tokio::time::sleep(FILE_EVENT_DEBOUNCE).await;
+                while rx.try_recv().is_ok() {}
+
+                info!("설정 파일 변경 감지");
+                let _ = reloader.reload_blocking().await;
+            }
+        });
+
+        Ok(watcher)
+    }
+
+    /// SIGHUP을 받을 때마다 설정을 다시 읽습니다.
+    #[cfg(unix)]
+    pub fn reload_on_sighup(self: &Arc<Self>) -> anyhow::Result<()> {
+        use tokio::signal::unix::{signal, SignalKind};
+
+        let mut hangup = signal(SignalKind::hangup())?;
+        let reloader = Arc::clone(self);
+        tokio::spawn(async move {
+            while hangup.recv().await.is_some() {
+                info!("SIGHUP 수신");
+                let _ = reloader.reload_blocking().await;
+            }
+        });
+
+        Ok(())
+    }
+
+    #[cfg(not(unix))]
+    pub fn reload_on_sighup(self: &Arc<Self>) -> anyhow::Result<()> {
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_restart_required_changes_are_rejected() {
+        let (reloader, receiver) = ConfigReloader::new(Arc::new(Settings::default()));
+
+        let mut settings = Settings::default();
+        settings.cloudwatch.period = 300;
+        assert!(reloader.apply(Ok(settings)).is_ok());
+        assert_eq!(receiver.borrow().cloudwatch.period, 300);
+
+        let mut settings = Settings::default();
+        settings.cloudwatch.period = 120;
+        settings.exporter.port = 9044;
+        let error = reloader.apply(Ok(settings)).unwrap_err();
+        assert!(error.to_string().contains("exporter"));
+        assert_eq!(receiver.borrow().cloudwatch.period, 300);
+        assert_eq!(CONFIG_LAST_RELOAD_SUCCESSFUL.get(), 0.0);
+    }
+}